@@ -30,9 +30,9 @@ fn weight(s: f32, r: f32, a: f32) -> f32 {
 }
 
 fn mul_add(dest: &mut [f32; 3], s: f32, x: f32, y: f32, z: f32) {
-  dest[0] = dest[0] + s * x;
-  dest[1] = dest[1] + s * y;
-  dest[2] = dest[2] + s * z;
+  dest[0] += s * x;
+  dest[1] += s * y;
+  dest[2] += s * z;
 }
 
 pub fn compute(
@@ -41,7 +41,7 @@ pub fn compute(
 ) -> Vec<[f32; 3]> {
     // Allocate normal array
     let mut normals: Vec<[f32; 3]> = positions
-        .into_iter()
+        .iter()
         .map(|_| [0.0, 0.0, 0.0])
         .collect();
 
@@ -78,9 +78,9 @@ pub fn compute(
         let mut ny = abz * bcx - abx * bcz;
         let mut nz = abx * bcy - aby * bcx;
         let nl = hypot(nx, ny, nz);
-        nx = nx / nl;
-        ny = ny / nl;
-        nz = nz / nl;
+        nx /= nl;
+        ny /= nl;
+        nz /= nl;
 
         mul_add(&mut normals[cell[0] as usize], weight(s, r, bc), nx, ny, nz);
         mul_add(&mut normals[cell[1] as usize], weight(s, r, ca), nx, ny, nz);
@@ -101,9 +101,9 @@ pub fn compute(
             n[2] = 0.0;
             continue;
         }
-        n[0] = n[0] / l;
-        n[1] = n[1] / l;
-        n[2] = n[2] / l;
+        n[0] /= l;
+        n[1] /= l;
+        n[2] /= l;
     }
 
     normals
@@ -114,9 +114,9 @@ pub fn look_at(eye: &Vec3, center: &Vec3, up: &Vec3) -> Mat4 {
     let mut z2 = eye[2] - center[2];
 
     let len_z = 1.0 / (z0 * z0 + z1 * z1 + z2 * z2).sqrt();
-    z0 = z0 * len_z;
-    z1 = z1 * len_z;
-    z2 = z2 * len_z;
+    z0 *= len_z;
+    z1 *= len_z;
+    z2 *= len_z;
 
     let mut x0 = up[1] * z2 - up[2] * z1;
     let mut x1 = up[2] * z0 - up[0] * z2;
@@ -128,7 +128,7 @@ pub fn look_at(eye: &Vec3, center: &Vec3, up: &Vec3) -> Mat4 {
         x1 = 0.0;
         x2 = 0.0;
     } else {
-        x0 = x0 / len_x;
+        x0 /= len_x;
         x1 = x0 / len_x;
         x2 = x0 / len_x;
     }
@@ -143,9 +143,9 @@ pub fn look_at(eye: &Vec3, center: &Vec3, up: &Vec3) -> Mat4 {
         y1 = 0.0;
         y2 = 0.0;
     } else {
-        y0 = y0 / len_y;
-        y1 = y1 / len_y;
-        y2 = y2 / len_y;
+        y0 /= len_y;
+        y1 /= len_y;
+        y2 /= len_y;
     }
 
     [
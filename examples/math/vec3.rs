@@ -20,24 +20,22 @@
 // THE SOFTWARE.
 #![allow(dead_code)]
 
-mod vec3 {
-    type Vec3 = [f64; 3];
+type Vec3 = [f64; 3];
 
-    pub fn add(a: &Vec3, b: &Vec3) -> Vec3 {
-        [ a[0] + b[0], a[1] + b[1], a[2] + b[2] ]
-    }
+pub fn add(a: &Vec3, b: &Vec3) -> Vec3 {
+    [ a[0] + b[0], a[1] + b[1], a[2] + b[2] ]
+}
 
-    pub fn subtract(a: &Vec3, b: &Vec3) -> Vec3 {
-        [ a[0] - b[0], a[1] - b[1], a[2] - b[2] ]
-    }
+pub fn subtract(a: &Vec3, b: &Vec3) -> Vec3 {
+    [ a[0] - b[0], a[1] - b[1], a[2] - b[2] ]
+}
 
-    pub fn multiply(a: &Vec3, b: &Vec3) -> Vec3 {
-        [ a[0] * b[0], a[1] * b[1], a[2] * b[2] ]
-    }
+pub fn multiply(a: &Vec3, b: &Vec3) -> Vec3 {
+    [ a[0] * b[0], a[1] * b[1], a[2] * b[2] ]
+}
 
-    pub fn dot(a: &Vec3, b: &Vec3) -> f64 {
-        a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
-    }
+pub fn dot(a: &Vec3, b: &Vec3) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
 }
 
 #[cfg(test)]
@@ -47,7 +45,7 @@ mod vec3_tests {
     #[test]
     fn vec3_add() {
         assert_eq!(
-            vec3::add(
+            add(
                 &[1.0, 10.0, 100.0],
                 &[2.0, 20.0, 200.0]
             ),
@@ -58,7 +56,7 @@ mod vec3_tests {
     #[test]
     fn vec3_subtract() {
         assert_eq!(
-            vec3::subtract(
+            subtract(
                 &[3.0, 30.0, 300.0],
                 &[1.0, 10.0, 100.0]
             ),
@@ -69,7 +67,7 @@ mod vec3_tests {
     #[test]
     fn vec3_multiply() {
         assert_eq!(
-            vec3::multiply(
+            multiply(
                 &[3.0, 30.0, 300.0],
                 &[2.0, 20.0, 200.0]
             ),
@@ -80,7 +78,7 @@ mod vec3_tests {
     #[test]
     fn vec3_dot() {
         assert_eq!(
-            vec3::dot(
+            dot(
                 &[3.0, 30.0, 300.0],
                 &[2.0, 20.0, 200.0]
             ),
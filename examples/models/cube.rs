@@ -1,4 +1,4 @@
-pub static POSITIONS: &'static [[f32; 3]] = &[
+pub static POSITIONS: &[[f32; 3]] = &[
     [
         -0.5,
         -0.5,
@@ -121,7 +121,7 @@ pub static POSITIONS: &'static [[f32; 3]] = &[
     ]
 ];
 
-pub static CELLS: &'static [[u32; 3]] = &[
+pub static CELLS: &[[u32; 3]] = &[
     [
         0,
         1,
@@ -184,7 +184,7 @@ pub static CELLS: &'static [[u32; 3]] = &[
     ]
 ];
 
-pub static NORMALS: &'static [[f32; 3]] = &[
+pub static NORMALS: &[[f32; 3]] = &[
     [
         0.0,
         0.0,
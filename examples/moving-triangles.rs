@@ -1,6 +1,9 @@
 #[macro_use]
 extern crate rugl;
 
+use rugl::FrameControl;
+use rugl::input::VirtualKeyCode;
+
 fn main() {
     let mut rugl = rugl::init();
     let count = 1000;
@@ -9,6 +12,7 @@ fn main() {
         .vert("
             #version 150
             in vec2 position;
+            // One id for each instance of the triangle.
             in float id;
 
             uniform float time;
@@ -44,26 +48,15 @@ fn main() {
         .uniform("color3", Box::new(|_| Box::new([0.0, 0.0, 1.0])))
         .uniform("time", Box::new(|env| Box::new(env.time as f32)))
         .uniform("count", {
-            let count_in = count.clone() as f32;
+            let count_in = count as f32;
             Box::new(move |_| Box::new(count_in))
         })
-        .attribute("position", {
-            &((0..(count * 3)).map(|i| {
-                // Equilateral triangle
-                match i % 3 {
-                    0 => [0.0, 0.5],
-                    1 => [0.36056, -0.5],
-                    2 => [-0.36056, -0.5],
-                    _ => panic!("Math is hard.")
-                }
-            }).collect::<Vec<[f32; 2]>>())
-        })
-        .attribute("id", {
-            &((0..(count * 3)).map(|i| {
-                (i as f32 / 3.0).floor()
-            }).collect::<Vec<f32>>())
-        })
-        .count(count * 3)
+        // Equilateral triangle
+        .attribute("position", &vec![[0.0f32, 0.5], [0.36056, -0.5], [-0.36056, -0.5]])
+        .attribute("id", &(0..count).map(|i| i as f32).collect::<Vec<f32>>())
+        .divisor("id", 1)
+        .instances(count)
+        .count(3)
         .finalize();
 
     let clear = rugl!(rugl.clear, {
@@ -74,5 +67,10 @@ fn main() {
     rugl.frame(|env| {
         clear();
         draw(env);
+
+        match env.input.was_key_pressed(VirtualKeyCode::Escape) {
+            true => FrameControl::Exit,
+            false => FrameControl::Continue
+        }
     });
 }
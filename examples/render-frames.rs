@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate rugl;
+
+use rugl::offline::RenderSettings;
+
+fn main() {
+    let mut rugl = rugl::init_headless();
+
+    let draw = rugl.draw()
+        .vert("
+            #version 150
+            in vec2 position;
+            uniform float time;
+            void main() {
+                float angle = time * 3.14159;
+                mat2 rotation = mat2(cos(angle), sin(angle), -sin(angle), cos(angle));
+                gl_Position = vec4(rotation * position, 0.0, 1.0);
+            }
+        ")
+        .frag("
+            #version 150
+            out vec4 out_color;
+            void main() {
+                out_color = vec4(1.0, 1.0, 1.0, 1.0);
+            }
+        ")
+        .attribute("position", &vec![
+             0.0f32,  0.5,
+             0.5, -0.5,
+            -0.5, -0.5
+        ])
+        .uniform("time", Box::new(|env| Box::new(env.time as f32)))
+        .count(3)
+        .finalize();
+
+    let clear = rugl!(rugl.clear, {
+        color => [0.3, 0.2, 0.3, 1.0],
+        depth => 1.0
+    });
+
+    // Two seconds of a spinning triangle, which can be turned into a video with
+    // `ffmpeg -i frames/triangle.y4m -pix_fmt yuv420p triangle.mp4`.
+    let settings = RenderSettings::new(640, 480, 60)
+        .fps(30)
+        .output("frames/triangle-")
+        .y4m("frames/triangle.y4m");
+
+    rugl.render(&settings, |env| {
+        clear();
+        draw(env);
+    }).expect("Unable to write the frames");
+}
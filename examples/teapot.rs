@@ -8,7 +8,29 @@ use math::angle_normals;
 extern crate rugl;
 
 fn main() {
-    let mut rugl = rugl::init();
+    let mut rugl = rugl::RuglBuilder::new()
+        .title("teapot")
+        .multisampling(4)
+        .depth_bits(24)
+        .build()
+        .unwrap();
+
+    // Every command drawn inside of the camera scope gets its projection and view.
+    let camera = rugl.scope()
+        .uniform("view", {
+            let view = mat4::translate(&mat4::identity(), &[0.0, 0.0, -50.0]);
+            Box::new(move |_| Box::new({
+                view
+            }))
+        })
+        .uniform("projection", {
+            Box::new(move |env| Box::new({
+                let aspect = env.viewport_width as f32 / env.viewport_height as f32;
+                let fovy = 1.0;
+                mat4::perspective(fovy, aspect, 0.1, 1000.0)
+            }))
+        })
+        .finalize();
 
     let draw = rugl.draw()
         .vert("
@@ -50,19 +72,6 @@ fn main() {
                 mat4::rotate_y(&identity, env.time as f32)
             }))
         })
-        .uniform("view", {
-            let view = mat4::translate(&mat4::identity(), &[0.0, 0.0, -50.0]);
-            Box::new(move |_| Box::new({
-                view
-            }))
-        })
-        .uniform("projection", {
-            Box::new(move |env| Box::new({
-                let aspect = env.viewport_width as f32 / env.viewport_height as f32;
-                let fovy = 1.0;
-                mat4::perspective(fovy, aspect, 0.1, 1000.0)
-            }))
-        })
         .finalize();
 
 
@@ -73,6 +82,6 @@ fn main() {
 
     rugl.frame(|env| {
         clear();
-        draw(env);
+        camera.run(env, |env| draw(env));
     });
 }
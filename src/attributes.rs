@@ -0,0 +1,366 @@
+use super::backend::GlBackend;
+use super::buffers::BufferableData;
+use super::gl;
+use super::gl::types::*;
+use super::gl_helpers;
+use super::gl_helpers::flatten;
+use std::mem;
+
+/// The type of each component of an attribute as it's stored in the buffer. The GL converts
+/// the components to the type that the shader declares.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ComponentType {
+    F32,
+    HalfFloat,
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    /// Only for `double` attributes in the shader.
+    F64
+}
+
+impl ComponentType {
+    pub fn to_gl_enum(&self) -> GLenum {
+        match *self {
+            ComponentType::F32 => gl::FLOAT,
+            ComponentType::HalfFloat => gl::HALF_FLOAT,
+            ComponentType::I8 => gl::BYTE,
+            ComponentType::U8 => gl::UNSIGNED_BYTE,
+            ComponentType::I16 => gl::SHORT,
+            ComponentType::U16 => gl::UNSIGNED_SHORT,
+            ComponentType::I32 => gl::INT,
+            ComponentType::U32 => gl::UNSIGNED_INT,
+            ComponentType::F64 => gl::DOUBLE
+        }
+    }
+
+    /// The size of a single component in bytes.
+    pub fn size(&self) -> usize {
+        match self {
+            &ComponentType::I8 | &ComponentType::U8 => 1,
+            &ComponentType::HalfFloat | &ComponentType::I16 | &ComponentType::U16 => 2,
+            &ComponentType::F32 | &ComponentType::I32 | &ComponentType::U32 => 4,
+            &ComponentType::F64 => 8
+        }
+    }
+}
+
+/// Describes how an attribute reads its values out of a buffer. The component type and count
+/// default to the type that the shader declares, which fits tightly packed `f32` data. Integer
+/// components are converted to floats, unless the shader declares an `int` or `uint` type, in
+/// which case they're passed through as they are.
+///
+///     use rugl::attributes::{Attribute, ComponentType};
+///
+///     // Interleaved vertices made up of three f32 positions and four u8 colors.
+///     let buffer = 1;
+///     let stride = 3 * 4 + 4;
+///     let position = Attribute::new(buffer).stride(stride);
+///     let color = Attribute::new(buffer)
+///         .component_type(ComponentType::U8)
+///         .components(4)
+///         .normalized(true)
+///         .stride(stride)
+///         .offset(3 * 4);
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Attribute {
+    pub buffer: GLuint,
+    pub component_type: Option<ComponentType>,
+    pub components: Option<i32>,
+    /// Map integer components to 0 to 1, or -1 to 1 for signed types, instead of converting
+    /// them to the same number as a float.
+    pub normalized: bool,
+    /// The bytes from the start of one vertex to the next, 0 for tightly packed values.
+    pub stride: i32,
+    /// The bytes from the start of the buffer to the first value.
+    pub offset: usize
+}
+
+impl Attribute {
+    pub fn new(buffer: GLuint) -> Attribute {
+        Attribute {
+            buffer,
+            component_type: None,
+            components: None,
+            normalized: false,
+            stride: 0,
+            offset: 0
+        }
+    }
+
+    pub fn component_type(mut self, component_type: ComponentType) -> Attribute {
+        self.component_type = Some(component_type);
+        self
+    }
+
+    /// How many components each vertex has, from 1 to 4.
+    pub fn components(mut self, components: i32) -> Attribute {
+        assert!((1..=4).contains(&components), "An attribute has 1 to 4 components");
+        self.components = Some(components);
+        self
+    }
+
+    /// This has no effect on attributes that the shader declares as integers.
+    pub fn normalized(mut self, normalized: bool) -> Attribute {
+        self.normalized = normalized;
+        self
+    }
+
+    pub fn stride(mut self, stride: i32) -> Attribute {
+        self.stride = stride;
+        self
+    }
+
+    pub fn offset(mut self, offset: usize) -> Attribute {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Anything that `DrawBuilder::attribute()` accepts. Vertex data is uploaded into a new
+/// buffer, while an `Attribute` reads from a buffer that already exists.
+pub trait IntoAttribute {
+    fn into_attribute(self, backend: &dyn GlBackend) -> Attribute;
+}
+
+impl IntoAttribute for Attribute {
+    fn into_attribute(self, _: &dyn GlBackend) -> Attribute {
+        self
+    }
+}
+
+impl<T: BufferableData + ?Sized> IntoAttribute for &T {
+    fn into_attribute(self, backend: &dyn GlBackend) -> Attribute {
+        let attribute = Attribute::new(self.to_buffer(backend));
+        match self.component_type() {
+            Some(component_type) => attribute.component_type(component_type),
+            None => attribute
+        }
+    }
+}
+
+/// The type of a field in a vertex struct, which decides how an attribute reads it. A `Vec` of
+/// any of these types is also `BufferableData`.
+pub trait VertexComponent {
+    fn component_type() -> ComponentType;
+    fn components() -> i32;
+}
+
+macro_rules! vertex_component {
+    ($component:ty, $component_type:expr, $components:expr) => {
+        impl VertexComponent for $component {
+            fn component_type() -> ComponentType {
+                $component_type
+            }
+
+            fn components() -> i32 {
+                $components
+            }
+        }
+    };
+}
+
+vertex_component!(f32, ComponentType::F32, 1);
+vertex_component!([f32; 2], ComponentType::F32, 2);
+vertex_component!([f32; 3], ComponentType::F32, 3);
+vertex_component!([f32; 4], ComponentType::F32, 4);
+vertex_component!(f64, ComponentType::F64, 1);
+vertex_component!([f64; 2], ComponentType::F64, 2);
+vertex_component!([f64; 3], ComponentType::F64, 3);
+vertex_component!([f64; 4], ComponentType::F64, 4);
+vertex_component!(i8, ComponentType::I8, 1);
+vertex_component!([i8; 2], ComponentType::I8, 2);
+vertex_component!([i8; 3], ComponentType::I8, 3);
+vertex_component!([i8; 4], ComponentType::I8, 4);
+vertex_component!(u8, ComponentType::U8, 1);
+vertex_component!([u8; 2], ComponentType::U8, 2);
+vertex_component!([u8; 3], ComponentType::U8, 3);
+vertex_component!([u8; 4], ComponentType::U8, 4);
+vertex_component!(i16, ComponentType::I16, 1);
+vertex_component!([i16; 2], ComponentType::I16, 2);
+vertex_component!([i16; 3], ComponentType::I16, 3);
+vertex_component!([i16; 4], ComponentType::I16, 4);
+vertex_component!(u16, ComponentType::U16, 1);
+vertex_component!([u16; 2], ComponentType::U16, 2);
+vertex_component!([u16; 3], ComponentType::U16, 3);
+vertex_component!([u16; 4], ComponentType::U16, 4);
+vertex_component!(i32, ComponentType::I32, 1);
+vertex_component!([i32; 2], ComponentType::I32, 2);
+vertex_component!([i32; 3], ComponentType::I32, 3);
+vertex_component!([i32; 4], ComponentType::I32, 4);
+vertex_component!(u32, ComponentType::U32, 1);
+vertex_component!([u32; 2], ComponentType::U32, 2);
+vertex_component!([u32; 3], ComponentType::U32, 3);
+vertex_component!([u32; 4], ComponentType::U32, 4);
+
+/// A field of a vertex struct, which is read by the shader attribute of the same name.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VertexField {
+    pub name: &'static str,
+    pub component_type: ComponentType,
+    pub components: i32,
+    /// The bytes from the start of the struct to the field.
+    pub offset: usize
+}
+
+impl VertexField {
+    /// Describe a field from a pointer to it, which is only used to pick its type.
+    pub fn new<C: VertexComponent>(name: &'static str, _: *const C, offset: usize) -> VertexField {
+        VertexField {
+            name,
+            component_type: C::component_type(),
+            components: C::components(),
+            offset
+        }
+    }
+}
+
+/// Describes the fields of a `#[repr(C)]` vertex struct, so that a `Vec` of them can be
+/// uploaded into a single interleaved buffer with `Rugl::vertex_buffer()`. Implement it with
+/// the `vertex_layout!` macro.
+///
+///     #[macro_use]
+///     extern crate rugl;
+///
+///     #[repr(C)]
+///     struct Vertex {
+///         position: [f32; 3],
+///         normal: [f32; 3],
+///         color: [u8; 4]
+///     }
+///
+///     vertex_layout!(Vertex { position, normal, color });
+///
+///     use rugl::attributes::VertexLayout;
+///     assert_eq!(Vertex::fields()[2].offset, 24);
+///
+pub trait VertexLayout {
+    fn fields() -> Vec<VertexField>;
+}
+
+/// Implement `VertexLayout` for a struct by listing the fields that shaders read. Generic
+/// structs list their type parameters, e.g. `vertex_layout!(Vertex<C> { position, color })`,
+/// and every parameter has to be a `VertexComponent`.
+#[macro_export]
+macro_rules! vertex_layout {
+    ($vertex:ident $(< $($param:ident),* >)* { $($field:ident),* }) => {
+        impl<$($($param: $crate::attributes::VertexComponent),*)*> $crate::attributes::VertexLayout
+            for $vertex $(< $($param),* >)*
+        {
+            fn fields() -> Vec<$crate::attributes::VertexField> {
+                // Measure the offsets on an uninitialized vertex, only the addresses of its
+                // fields are taken.
+                let vertex = ::std::mem::MaybeUninit::<Self>::uninit();
+                let start = vertex.as_ptr();
+                vec![$({
+                    let field = unsafe { ::std::ptr::addr_of!((*start).$field) };
+                    $crate::attributes::VertexField::new(
+                        stringify!($field),
+                        field,
+                        field as usize - start as usize
+                    )
+                }),*]
+            }
+        }
+    };
+}
+
+/// A buffer of interleaved vertices, created with `Rugl::vertex_buffer()`. Pass it to
+/// `DrawBuilder::vertex_buffer()` to read every field with the attribute of the same name, or
+/// pick single fields with `attribute()`.
+pub struct VertexBuffer {
+    pub buffer: GLuint,
+    /// The size of a vertex in bytes.
+    pub stride: i32,
+    pub fields: Vec<VertexField>
+}
+
+impl VertexBuffer {
+    pub fn new<T: VertexLayout>(backend: &dyn GlBackend, vertices: &[T]) -> VertexBuffer {
+        VertexBuffer {
+            buffer: gl_helpers::create_buffer_from_bytes(backend, unsafe { flatten(vertices) }),
+            stride: mem::size_of::<T>() as i32,
+            fields: T::fields()
+        }
+    }
+
+    /// Read a single field of the vertices. This panics if there's no field of that name.
+    pub fn attribute(&self, name: &str) -> Attribute {
+        let field = match self.fields.iter().find(|field| field.name == name) {
+            Some(field) => field,
+            None => panic!("The vertices don't have a field named {:?}", name)
+        };
+
+        Attribute::new(self.buffer)
+            .component_type(field.component_type)
+            .components(field.components)
+            .stride(self.stride)
+            .offset(field.offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::recording_backend::RecordingBackend;
+
+    #[repr(C)]
+    struct Vertex {
+        position: [f32; 3],
+        color: [u8; 4],
+        uv: [u16; 2]
+    }
+
+    vertex_layout!(Vertex { position, color, uv });
+
+    #[repr(C)]
+    struct GenericVertex<P, C> {
+        position: P,
+        color: C
+    }
+
+    vertex_layout!(GenericVertex<P, C> { position, color });
+
+    #[test]
+    fn fields_are_read_from_one_interleaved_buffer() {
+        let backend = RecordingBackend::new();
+        let vertices = vec![
+            Vertex { position: [0.0, 0.5, 0.0], color: [255, 0, 0, 255], uv: [0, 0] },
+            Vertex { position: [0.5, -0.5, 0.0], color: [0, 255, 0, 255], uv: [0, 1] }
+        ];
+        let vertex_buffer = VertexBuffer::new(&backend, &vertices);
+
+        assert_eq!(vertex_buffer.stride, 20);
+        assert_eq!(vertex_buffer.fields.len(), 3);
+        assert_eq!(
+            vertex_buffer.attribute("uv"),
+            Attribute::new(vertex_buffer.buffer)
+                .component_type(ComponentType::U16)
+                .components(2)
+                .stride(20)
+                .offset(16)
+        );
+    }
+
+    #[test]
+    fn generic_vertices_use_the_types_of_their_parameters() {
+        let fields = GenericVertex::<[u16; 2], [i8; 3]>::fields();
+
+        assert_eq!(fields[0], VertexField {
+            name: "position",
+            component_type: ComponentType::U16,
+            components: 2,
+            offset: 0
+        });
+        assert_eq!(fields[1], VertexField {
+            name: "color",
+            component_type: ComponentType::I8,
+            components: 3,
+            offset: 4
+        });
+    }
+}
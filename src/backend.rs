@@ -0,0 +1,655 @@
+use super::gl;
+use super::gl::types::*;
+use std::ffi::CString;
+use std::mem;
+use std::ptr;
+use std::str;
+
+/// The name, array size and type enum of an active attribute or uniform, as reported by
+/// `glGetActiveAttrib` and `glGetActiveUniform`.
+pub struct ActiveInfo {
+    pub name: String,
+    pub data_size: GLint,
+    pub data_type: GLenum
+}
+
+/// Every GL call that rugl makes goes through this trait. `NativeBackend` calls into the
+/// loaded GL function pointers, while the `RecordingBackend` can be used to test commands
+/// without a driver.
+pub trait GlBackend {
+    // Shaders and programs
+    fn create_shader(&self, shader_type: GLenum) -> GLuint;
+    fn shader_source(&self, shader: GLuint, source: &str);
+    fn compile_shader(&self, shader: GLuint);
+    fn get_shader_iv(&self, shader: GLuint, parameter: GLenum) -> GLint;
+    fn get_shader_info_log(&self, shader: GLuint) -> String;
+    fn create_program(&self) -> GLuint;
+    fn attach_shader(&self, program: GLuint, shader: GLuint);
+    fn link_program(&self, program: GLuint);
+    fn get_program_iv(&self, program: GLuint, parameter: GLenum) -> GLint;
+    fn get_program_info_log(&self, program: GLuint) -> String;
+    fn use_program(&self, program: GLuint);
+    fn get_active_attrib(&self, program: GLuint, index: GLuint) -> ActiveInfo;
+    fn get_active_uniform(&self, program: GLuint, index: GLuint) -> ActiveInfo;
+    fn get_uniform_location(&self, program: GLuint, name: &str) -> GLint;
+
+    // Uniforms, the count is inferred from the length of the values.
+    fn uniform_fv(&self, location: GLint, components: GLint, values: &[GLfloat]);
+    fn uniform_iv(&self, location: GLint, components: GLint, values: &[GLint]);
+    fn uniform_uiv(&self, location: GLint, components: GLint, values: &[GLuint]);
+    fn uniform_matrix_fv(&self, location: GLint, columns: GLint, rows: GLint, values: &[GLfloat]);
+
+    // Buffers and vertex arrays
+    fn gen_buffer(&self) -> GLuint;
+    fn bind_buffer(&self, target: GLenum, buffer: GLuint);
+    fn buffer_data(&self, target: GLenum, data: &[u8], usage: GLenum);
+    fn gen_vertex_array(&self) -> GLuint;
+    fn bind_vertex_array(&self, vao: GLuint);
+    fn delete_vertex_array(&self, vao: GLuint);
+    fn enable_vertex_attrib_array(&self, index: GLuint);
+    fn vertex_attrib_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        data_type: GLenum,
+        normalized: bool,
+        stride: GLsizei,
+        offset: usize
+    );
+    /// Point an `int` or `uint` attribute at integer data, which reaches the shader unconverted.
+    fn vertex_attrib_i_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        data_type: GLenum,
+        stride: GLsizei,
+        offset: usize
+    );
+    /// Point a `double` attribute at 64 bit floats.
+    fn vertex_attrib_l_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        data_type: GLenum,
+        stride: GLsizei,
+        offset: usize
+    );
+    /// Advance the attribute once every `divisor` instances instead of once per vertex.
+    fn vertex_attrib_divisor(&self, index: GLuint, divisor: GLuint);
+    /// The value of an attribute whose array is disabled, which every vertex reads.
+    fn vertex_attrib_4f(&self, index: GLuint, x: GLfloat, y: GLfloat, z: GLfloat, w: GLfloat);
+    fn vertex_attrib_i4i(&self, index: GLuint, x: GLint, y: GLint, z: GLint, w: GLint);
+    fn vertex_attrib_i4ui(&self, index: GLuint, x: GLuint, y: GLuint, z: GLuint, w: GLuint);
+
+    // Framebuffers
+    fn gen_framebuffer(&self) -> GLuint;
+    fn bind_framebuffer(&self, target: GLenum, framebuffer: GLuint);
+    fn gen_renderbuffer(&self) -> GLuint;
+    fn bind_renderbuffer(&self, renderbuffer: GLuint);
+    fn renderbuffer_storage(&self, internal_format: GLenum, width: GLsizei, height: GLsizei);
+    fn framebuffer_renderbuffer(&self, attachment: GLenum, renderbuffer: GLuint);
+    fn check_framebuffer_status(&self, target: GLenum) -> GLenum;
+    fn delete_framebuffer(&self, framebuffer: GLuint);
+    fn delete_renderbuffer(&self, renderbuffer: GLuint);
+
+    // Drawing and state
+    fn clear_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat);
+    fn clear_depth(&self, depth: GLdouble);
+    fn clear_stencil(&self, stencil: GLint);
+    fn clear(&self, mask: GLbitfield);
+    fn enable(&self, capability: GLenum);
+    fn disable(&self, capability: GLenum);
+    fn blend_func_separate(
+        &self,
+        src_rgb: GLenum,
+        dst_rgb: GLenum,
+        src_alpha: GLenum,
+        dst_alpha: GLenum
+    );
+    fn blend_equation_separate(&self, mode_rgb: GLenum, mode_alpha: GLenum);
+    fn blend_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat);
+    fn depth_func(&self, func: GLenum);
+    fn depth_mask(&self, mask: bool);
+    fn depth_range(&self, near: GLdouble, far: GLdouble);
+    fn cull_face(&self, mode: GLenum);
+    fn front_face(&self, mode: GLenum);
+    fn polygon_offset(&self, factor: GLfloat, units: GLfloat);
+    fn stencil_mask(&self, mask: GLuint);
+    fn stencil_func(&self, func: GLenum, reference: GLint, mask: GLuint);
+    fn stencil_op_separate(&self, face: GLenum, fail: GLenum, zfail: GLenum, zpass: GLenum);
+    fn viewport(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei);
+    fn scissor(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei);
+    fn color_mask(&self, red: bool, green: bool, blue: bool, alpha: bool);
+    fn line_width(&self, width: GLfloat);
+    fn point_size(&self, size: GLfloat);
+    fn draw_arrays(&self, mode: GLenum, first: GLint, count: GLsizei);
+    fn draw_elements(&self, mode: GLenum, count: GLsizei, data_type: GLenum, offset: usize);
+    fn draw_arrays_instanced(&self, mode: GLenum, first: GLint, count: GLsizei, instances: GLsizei);
+    fn draw_elements_instanced(
+        &self,
+        mode: GLenum,
+        count: GLsizei,
+        data_type: GLenum,
+        offset: usize,
+        instances: GLsizei
+    );
+    fn finish(&self);
+    /// Read a rectangle of the current read framebuffer into `data`, which must be large
+    /// enough to hold it. Rows are returned bottom to top, as the GL stores them.
+    // Takes the same arguments as glReadPixels.
+    #[allow(clippy::too_many_arguments)]
+    fn read_pixels(
+        &self,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: GLenum,
+        pixel_type: GLenum,
+        data: &mut [u8]
+    );
+    fn get_integer_v(&self, parameter: GLenum) -> GLint;
+    /// Get a parameter made up of several floats, e.g. `gl::ALIASED_LINE_WIDTH_RANGE`.
+    fn get_float_v(&self, parameter: GLenum, values: &mut [GLfloat]);
+    fn get_error(&self) -> GLenum;
+}
+
+/// Dispatch to the global GL function pointers loaded by `gl::load_with`.
+pub struct NativeBackend;
+
+impl GlBackend for NativeBackend {
+    fn create_shader(&self, shader_type: GLenum) -> GLuint {
+        unsafe { gl::CreateShader(shader_type) }
+    }
+
+    fn shader_source(&self, shader: GLuint, source: &str) {
+        let c_str = CString::new(source.as_bytes()).unwrap();
+        unsafe { gl::ShaderSource(shader, 1, &c_str.as_ptr(), ptr::null()) }
+    }
+
+    fn compile_shader(&self, shader: GLuint) {
+        unsafe { gl::CompileShader(shader) }
+    }
+
+    fn get_shader_iv(&self, shader: GLuint, parameter: GLenum) -> GLint {
+        unsafe {
+            let mut value: GLint = 0;
+            gl::GetShaderiv(shader, parameter, &mut value);
+            value
+        }
+    }
+
+    fn get_shader_info_log(&self, shader: GLuint) -> String {
+        unsafe {
+            let log_length = self.get_shader_iv(shader, gl::INFO_LOG_LENGTH);
+            if log_length <= 1 {
+                return String::new();
+            }
+            let mut log = vec![0u8; log_length as usize];
+            gl::GetShaderInfoLog(shader, log_length, ptr::null_mut(), log.as_mut_ptr() as *mut GLchar);
+            log.pop(); // skip the trailing null character
+            str::from_utf8(&log).expect("ShaderInfoLog not valid utf8").to_string()
+        }
+    }
+
+    fn create_program(&self) -> GLuint {
+        unsafe { gl::CreateProgram() }
+    }
+
+    fn attach_shader(&self, program: GLuint, shader: GLuint) {
+        unsafe { gl::AttachShader(program, shader) }
+    }
+
+    fn link_program(&self, program: GLuint) {
+        unsafe { gl::LinkProgram(program) }
+    }
+
+    fn get_program_iv(&self, program: GLuint, parameter: GLenum) -> GLint {
+        unsafe {
+            let mut value: GLint = 0;
+            gl::GetProgramiv(program, parameter, &mut value);
+            value
+        }
+    }
+
+    fn get_program_info_log(&self, program: GLuint) -> String {
+        unsafe {
+            let log_length = self.get_program_iv(program, gl::INFO_LOG_LENGTH);
+            if log_length <= 1 {
+                return String::new();
+            }
+            let mut log = vec![0u8; log_length as usize];
+            gl::GetProgramInfoLog(program, log_length, ptr::null_mut(), log.as_mut_ptr() as *mut GLchar);
+            log.pop(); // skip the trailing null character
+            str::from_utf8(&log).expect("ProgramInfoLog not valid utf8").to_string()
+        }
+    }
+
+    fn use_program(&self, program: GLuint) {
+        unsafe { gl::UseProgram(program) }
+    }
+
+    fn get_active_attrib(&self, program: GLuint, index: GLuint) -> ActiveInfo {
+        unsafe {
+            let max_name_length: GLsizei = 127;
+            let mut name_buffer: Vec<u8> = Vec::with_capacity(128);
+            let mut name_length: GLsizei = 0;
+            let mut data_size: GLint = 0;
+            let mut data_type: GLenum = 0;
+
+            gl::GetActiveAttrib(
+                program,
+                index,
+                max_name_length,
+                &mut name_length,
+                &mut data_size,
+                &mut data_type,
+                name_buffer.as_mut_ptr() as *mut GLchar
+            );
+
+            name_buffer.set_len(name_length as usize);
+            ActiveInfo {
+                name: String::from_utf8(name_buffer).unwrap(),
+                data_size,
+                data_type
+            }
+        }
+    }
+
+    fn get_active_uniform(&self, program: GLuint, index: GLuint) -> ActiveInfo {
+        unsafe {
+            let max_name_length: GLsizei = 127;
+            let mut name_buffer: Vec<u8> = Vec::with_capacity(128);
+            let mut name_length: GLsizei = 0;
+            let mut data_size: GLint = 0;
+            let mut data_type: GLenum = 0;
+
+            gl::GetActiveUniform(
+                program,
+                index,
+                max_name_length,
+                &mut name_length,
+                &mut data_size,
+                &mut data_type,
+                name_buffer.as_mut_ptr() as *mut GLchar
+            );
+
+            name_buffer.set_len(name_length as usize);
+            ActiveInfo {
+                name: String::from_utf8(name_buffer).unwrap(),
+                data_size,
+                data_type
+            }
+        }
+    }
+
+    fn get_uniform_location(&self, program: GLuint, name: &str) -> GLint {
+        let c_str = CString::new(name.as_bytes()).unwrap();
+        unsafe { gl::GetUniformLocation(program, c_str.as_ptr()) }
+    }
+
+    fn uniform_fv(&self, location: GLint, components: GLint, values: &[GLfloat]) {
+        let count = (values.len() as GLint / components) as GLsizei;
+        unsafe {
+            match components {
+                1 => gl::Uniform1fv(location, count, values.as_ptr()),
+                2 => gl::Uniform2fv(location, count, values.as_ptr()),
+                3 => gl::Uniform3fv(location, count, values.as_ptr()),
+                4 => gl::Uniform4fv(location, count, values.as_ptr()),
+                _ => panic!("Uniform vectors must have between 1 and 4 components")
+            }
+        }
+    }
+
+    fn uniform_iv(&self, location: GLint, components: GLint, values: &[GLint]) {
+        let count = (values.len() as GLint / components) as GLsizei;
+        unsafe {
+            match components {
+                1 => gl::Uniform1iv(location, count, values.as_ptr()),
+                2 => gl::Uniform2iv(location, count, values.as_ptr()),
+                3 => gl::Uniform3iv(location, count, values.as_ptr()),
+                4 => gl::Uniform4iv(location, count, values.as_ptr()),
+                _ => panic!("Uniform vectors must have between 1 and 4 components")
+            }
+        }
+    }
+
+    fn uniform_uiv(&self, location: GLint, components: GLint, values: &[GLuint]) {
+        let count = (values.len() as GLint / components) as GLsizei;
+        unsafe {
+            match components {
+                1 => gl::Uniform1uiv(location, count, values.as_ptr()),
+                2 => gl::Uniform2uiv(location, count, values.as_ptr()),
+                3 => gl::Uniform3uiv(location, count, values.as_ptr()),
+                4 => gl::Uniform4uiv(location, count, values.as_ptr()),
+                _ => panic!("Uniform vectors must have between 1 and 4 components")
+            }
+        }
+    }
+
+    fn uniform_matrix_fv(&self, location: GLint, columns: GLint, rows: GLint, values: &[GLfloat]) {
+        let count = (values.len() as GLint / (columns * rows)) as GLsizei;
+        let transpose = gl::FALSE;
+        unsafe {
+            match (columns, rows) {
+                (2, 2) => gl::UniformMatrix2fv(location, count, transpose, values.as_ptr()),
+                (3, 3) => gl::UniformMatrix3fv(location, count, transpose, values.as_ptr()),
+                (4, 4) => gl::UniformMatrix4fv(location, count, transpose, values.as_ptr()),
+                (2, 3) => gl::UniformMatrix2x3fv(location, count, transpose, values.as_ptr()),
+                (3, 2) => gl::UniformMatrix3x2fv(location, count, transpose, values.as_ptr()),
+                (2, 4) => gl::UniformMatrix2x4fv(location, count, transpose, values.as_ptr()),
+                (4, 2) => gl::UniformMatrix4x2fv(location, count, transpose, values.as_ptr()),
+                (3, 4) => gl::UniformMatrix3x4fv(location, count, transpose, values.as_ptr()),
+                (4, 3) => gl::UniformMatrix4x3fv(location, count, transpose, values.as_ptr()),
+                _ => panic!("Uniform matrices must have between 2 and 4 columns and rows")
+            }
+        }
+    }
+
+    fn gen_buffer(&self) -> GLuint {
+        unsafe {
+            let mut buffer: GLuint = 0;
+            gl::GenBuffers(1, &mut buffer);
+            buffer
+        }
+    }
+
+    fn bind_buffer(&self, target: GLenum, buffer: GLuint) {
+        unsafe { gl::BindBuffer(target, buffer) }
+    }
+
+    fn buffer_data(&self, target: GLenum, data: &[u8], usage: GLenum) {
+        unsafe {
+            gl::BufferData(
+                target,
+                data.len() as GLsizeiptr,
+                data.as_ptr() as *const _,
+                usage
+            )
+        }
+    }
+
+    fn gen_vertex_array(&self) -> GLuint {
+        unsafe {
+            let mut vao: GLuint = 0;
+            gl::GenVertexArrays(1, &mut vao);
+            vao
+        }
+    }
+
+    fn bind_vertex_array(&self, vao: GLuint) {
+        unsafe { gl::BindVertexArray(vao) }
+    }
+
+    fn delete_vertex_array(&self, vao: GLuint) {
+        unsafe { gl::DeleteVertexArrays(1, &vao) }
+    }
+
+    fn enable_vertex_attrib_array(&self, index: GLuint) {
+        unsafe { gl::EnableVertexAttribArray(index) }
+    }
+
+    fn vertex_attrib_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        data_type: GLenum,
+        normalized: bool,
+        stride: GLsizei,
+        offset: usize
+    ) {
+        unsafe {
+            gl::VertexAttribPointer(
+                index,
+                size,
+                data_type,
+                normalized as GLboolean,
+                stride,
+                offset as *const _
+            )
+        }
+    }
+
+    fn vertex_attrib_i_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        data_type: GLenum,
+        stride: GLsizei,
+        offset: usize
+    ) {
+        unsafe { gl::VertexAttribIPointer(index, size, data_type, stride, offset as *const _) }
+    }
+
+    fn vertex_attrib_l_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        data_type: GLenum,
+        stride: GLsizei,
+        offset: usize
+    ) {
+        unsafe { gl::VertexAttribLPointer(index, size, data_type, stride, offset as *const _) }
+    }
+
+    fn vertex_attrib_divisor(&self, index: GLuint, divisor: GLuint) {
+        unsafe { gl::VertexAttribDivisor(index, divisor) }
+    }
+
+    fn vertex_attrib_4f(&self, index: GLuint, x: GLfloat, y: GLfloat, z: GLfloat, w: GLfloat) {
+        unsafe { gl::VertexAttrib4f(index, x, y, z, w) }
+    }
+
+    fn vertex_attrib_i4i(&self, index: GLuint, x: GLint, y: GLint, z: GLint, w: GLint) {
+        unsafe { gl::VertexAttribI4i(index, x, y, z, w) }
+    }
+
+    fn vertex_attrib_i4ui(&self, index: GLuint, x: GLuint, y: GLuint, z: GLuint, w: GLuint) {
+        unsafe { gl::VertexAttribI4ui(index, x, y, z, w) }
+    }
+
+    fn gen_framebuffer(&self) -> GLuint {
+        unsafe {
+            let mut framebuffer: GLuint = 0;
+            gl::GenFramebuffers(1, &mut framebuffer);
+            framebuffer
+        }
+    }
+
+    fn bind_framebuffer(&self, target: GLenum, framebuffer: GLuint) {
+        unsafe { gl::BindFramebuffer(target, framebuffer) }
+    }
+
+    fn gen_renderbuffer(&self) -> GLuint {
+        unsafe {
+            let mut renderbuffer: GLuint = 0;
+            gl::GenRenderbuffers(1, &mut renderbuffer);
+            renderbuffer
+        }
+    }
+
+    fn bind_renderbuffer(&self, renderbuffer: GLuint) {
+        unsafe { gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer) }
+    }
+
+    fn renderbuffer_storage(&self, internal_format: GLenum, width: GLsizei, height: GLsizei) {
+        unsafe { gl::RenderbufferStorage(gl::RENDERBUFFER, internal_format, width, height) }
+    }
+
+    fn framebuffer_renderbuffer(&self, attachment: GLenum, renderbuffer: GLuint) {
+        unsafe {
+            gl::FramebufferRenderbuffer(gl::FRAMEBUFFER, attachment, gl::RENDERBUFFER, renderbuffer)
+        }
+    }
+
+    fn check_framebuffer_status(&self, target: GLenum) -> GLenum {
+        unsafe { gl::CheckFramebufferStatus(target) }
+    }
+
+    fn delete_framebuffer(&self, framebuffer: GLuint) {
+        unsafe { gl::DeleteFramebuffers(1, &framebuffer) }
+    }
+
+    fn delete_renderbuffer(&self, renderbuffer: GLuint) {
+        unsafe { gl::DeleteRenderbuffers(1, &renderbuffer) }
+    }
+
+    fn clear_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+        unsafe { gl::ClearColor(red, green, blue, alpha) }
+    }
+
+    fn clear_depth(&self, depth: GLdouble) {
+        unsafe { gl::ClearDepth(depth) }
+    }
+
+    fn clear_stencil(&self, stencil: GLint) {
+        unsafe { gl::ClearStencil(stencil) }
+    }
+
+    fn clear(&self, mask: GLbitfield) {
+        unsafe { gl::Clear(mask) }
+    }
+
+    fn enable(&self, capability: GLenum) {
+        unsafe { gl::Enable(capability) }
+    }
+
+    fn disable(&self, capability: GLenum) {
+        unsafe { gl::Disable(capability) }
+    }
+
+    fn blend_func_separate(
+        &self,
+        src_rgb: GLenum,
+        dst_rgb: GLenum,
+        src_alpha: GLenum,
+        dst_alpha: GLenum
+    ) {
+        unsafe { gl::BlendFuncSeparate(src_rgb, dst_rgb, src_alpha, dst_alpha) }
+    }
+
+    fn blend_equation_separate(&self, mode_rgb: GLenum, mode_alpha: GLenum) {
+        unsafe { gl::BlendEquationSeparate(mode_rgb, mode_alpha) }
+    }
+
+    fn blend_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+        unsafe { gl::BlendColor(red, green, blue, alpha) }
+    }
+
+    fn depth_func(&self, func: GLenum) {
+        unsafe { gl::DepthFunc(func) }
+    }
+
+    fn depth_mask(&self, mask: bool) {
+        unsafe { gl::DepthMask(mask as GLboolean) }
+    }
+
+    fn depth_range(&self, near: GLdouble, far: GLdouble) {
+        unsafe { gl::DepthRange(near, far) }
+    }
+
+    fn cull_face(&self, mode: GLenum) {
+        unsafe { gl::CullFace(mode) }
+    }
+
+    fn front_face(&self, mode: GLenum) {
+        unsafe { gl::FrontFace(mode) }
+    }
+
+    fn polygon_offset(&self, factor: GLfloat, units: GLfloat) {
+        unsafe { gl::PolygonOffset(factor, units) }
+    }
+
+    fn stencil_mask(&self, mask: GLuint) {
+        unsafe { gl::StencilMask(mask) }
+    }
+
+    fn stencil_func(&self, func: GLenum, reference: GLint, mask: GLuint) {
+        unsafe { gl::StencilFunc(func, reference, mask) }
+    }
+
+    fn stencil_op_separate(&self, face: GLenum, fail: GLenum, zfail: GLenum, zpass: GLenum) {
+        unsafe { gl::StencilOpSeparate(face, fail, zfail, zpass) }
+    }
+
+    fn viewport(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+        unsafe { gl::Viewport(x, y, width, height) }
+    }
+
+    fn scissor(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+        unsafe { gl::Scissor(x, y, width, height) }
+    }
+
+    fn color_mask(&self, red: bool, green: bool, blue: bool, alpha: bool) {
+        unsafe {
+            gl::ColorMask(red as GLboolean, green as GLboolean, blue as GLboolean, alpha as GLboolean)
+        }
+    }
+
+    fn line_width(&self, width: GLfloat) {
+        unsafe { gl::LineWidth(width) }
+    }
+
+    fn point_size(&self, size: GLfloat) {
+        unsafe { gl::PointSize(size) }
+    }
+
+    fn draw_arrays(&self, mode: GLenum, first: GLint, count: GLsizei) {
+        unsafe { gl::DrawArrays(mode, first, count) }
+    }
+
+    fn draw_elements(&self, mode: GLenum, count: GLsizei, data_type: GLenum, offset: usize) {
+        unsafe { gl::DrawElements(mode, count, data_type, offset as *const _) }
+    }
+
+    fn draw_arrays_instanced(&self, mode: GLenum, first: GLint, count: GLsizei, instances: GLsizei) {
+        unsafe { gl::DrawArraysInstanced(mode, first, count, instances) }
+    }
+
+    fn draw_elements_instanced(
+        &self,
+        mode: GLenum,
+        count: GLsizei,
+        data_type: GLenum,
+        offset: usize,
+        instances: GLsizei
+    ) {
+        unsafe { gl::DrawElementsInstanced(mode, count, data_type, offset as *const _, instances) }
+    }
+
+    fn finish(&self) {
+        unsafe { gl::Finish() }
+    }
+
+    fn read_pixels(
+        &self,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: GLenum,
+        pixel_type: GLenum,
+        data: &mut [u8]
+    ) {
+        unsafe {
+            gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+            gl::ReadPixels(x, y, width, height, format, pixel_type, data.as_mut_ptr() as *mut _);
+        }
+    }
+
+    fn get_integer_v(&self, parameter: GLenum) -> GLint {
+        unsafe {
+            let mut value: GLint = 0;
+            gl::GetIntegerv(parameter, &mut value);
+            value
+        }
+    }
+
+    fn get_float_v(&self, parameter: GLenum, values: &mut [GLfloat]) {
+        unsafe { gl::GetFloatv(parameter, values.as_mut_ptr()) }
+    }
+
+    fn get_error(&self) -> GLenum {
+        unsafe { gl::GetError() }
+    }
+}
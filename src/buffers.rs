@@ -1,135 +1,141 @@
+use super::attributes::ComponentType;
+use super::backend::GlBackend;
 use super::gl::types::*;
 use super::Primitive;
 use super::gl_helpers::create_buffer;
 use super::gl_helpers::create_buffer_u32;
-use std::mem;
-use std::slice;
+use super::gl_helpers::create_buffer_from_bytes;
+use super::gl_helpers::flatten;
 
 // Take a reference to some vector data, and pretend to transfer it over to the gl state.
-pub trait BufferableData { fn to_buffer(&self) -> GLuint; }
+pub trait BufferableData {
+    fn to_buffer(&self, _: &dyn GlBackend) -> GLuint;
+
+    /// The type of the components in the buffer, or `None` to use the type of the shader.
+    fn component_type(&self) -> Option<ComponentType> {
+        None
+    }
+}
 
 impl BufferableData for Vec<f32> {
-    fn to_buffer(&self) -> GLuint {
-        create_buffer(self)
+    fn to_buffer(&self, backend: &dyn GlBackend) -> GLuint {
+        create_buffer(backend, self)
     }
 }
 
-/**
- * For all the rest of the code, we have to unsafely transmute our Vec<[f32; N]>
- * from a Vector, to a reference to slice. The actual data that the Vec points
- * to in the heap is correctly laid out how we want to use it on the GL side.
- * The basic process is to pull a pointer to our data, unsafely transmute_copy
- * a pointer to it, removing the type from the compiler. Finally we pass that
- * into the foreign function interface to be consumed by the gl state machine.
- */
-
-/**
- * TODO - Per jimb, consider moving the unsafe code:
- *        impl ReshapeSlice<f32> for [f32;3]
- *        and then `BufferableData` would just call `self.reshape`
- *        or ReshapeSlice<f32>::reshape(self) for Universal Function Call Syntax
+/*
+ * For all the rest of the code, we have to unsafely re-interpret our Vec<[f32; N]>
+ * as a flat slice of f32. The actual data that the Vec points to in the heap is
+ * correctly laid out how we want to use it on the GL side. The `flatten` helper
+ * takes a pointer to our data and builds a slice over the individual components,
+ * which is then passed into the foreign function interface to be consumed by the
+ * gl state machine.
  */
 
 impl BufferableData for Vec<[f32; 2]> {
-    fn to_buffer(&self) -> GLuint {
-        unsafe {
-            let data: &[f32] = slice::from_raw_parts(
-                mem::transmute_copy::<*const [f32; 2], *mut f32>(&self.as_ptr()),
-                self.len() * 2
-            );
-            create_buffer(data)
-        }
+    fn to_buffer(&self, backend: &dyn GlBackend) -> GLuint {
+        create_buffer(backend, unsafe { flatten(self.as_slice()) })
     }
 }
 
 impl BufferableData for Vec<[f32; 3]> {
-    fn to_buffer(&self) -> GLuint {
-        unsafe {
-            let vec: Vec<f32> = Vec::from_raw_parts(
-                mem::transmute_copy::<*const [f32; 3], *mut f32>(&self.as_ptr()),
-                self.len() * 3,
-                self.len() * 3
-            );
-            create_buffer(&vec)
-        }
+    fn to_buffer(&self, backend: &dyn GlBackend) -> GLuint {
+        create_buffer(backend, unsafe { flatten(self.as_slice()) })
     }
 }
 
 impl BufferableData for Vec<[f32; 4]> {
-    fn to_buffer(&self) -> GLuint {
-        unsafe {
-            let vec: Vec<f32> = Vec::from_raw_parts(
-                mem::transmute_copy::<*const [f32; 4], *mut f32>(&self.as_ptr()),
-                self.len() * 4,
-                self.len() * 4
-            );
-            create_buffer(&vec)
-        }
+    fn to_buffer(&self, backend: &dyn GlBackend) -> GLuint {
+        create_buffer(backend, unsafe { flatten(self.as_slice()) })
     }
 }
 
 impl BufferableData for Vec<[f32; 9]> {
-    fn to_buffer(&self) -> GLuint {
-        unsafe {
-            let vec: Vec<f32> = Vec::from_raw_parts(
-                mem::transmute_copy::<*const [f32; 9], *mut f32>(&self.as_ptr()),
-                self.len() * 9,
-                self.len() * 9
-            );
-            create_buffer(&vec)
-        }
+    fn to_buffer(&self, backend: &dyn GlBackend) -> GLuint {
+        create_buffer(backend, unsafe { flatten(self.as_slice()) })
     }
 }
 
 impl BufferableData for Vec<[f32; 12]> {
-    fn to_buffer(&self) -> GLuint {
-        unsafe {
-            let vec: Vec<f32> = Vec::from_raw_parts(
-                mem::transmute_copy::<*const [f32; 12], *mut f32>(&self.as_ptr()),
-                self.len() * 12,
-                self.len() * 12
-            );
-            create_buffer(&vec)
-        }
+    fn to_buffer(&self, backend: &dyn GlBackend) -> GLuint {
+        create_buffer(backend, unsafe { flatten(self.as_slice()) })
     }
 }
 
 impl BufferableData for Vec<[f32; 16]> {
-    fn to_buffer(&self) -> GLuint {
-        unsafe {
-            let vec: Vec<f32> = Vec::from_raw_parts(
-                mem::transmute_copy::<*const [f32; 16], *mut f32>(&self.as_ptr()),
-                self.len() * 16,
-                self.len() * 16
-            );
-            create_buffer(&vec)
-        }
+    fn to_buffer(&self, backend: &dyn GlBackend) -> GLuint {
+        create_buffer(backend, unsafe { flatten(self.as_slice()) })
     }
 }
 
 impl BufferableData for &'static [[f32; 3]] {
-    fn to_buffer(&self) -> GLuint {
-        unsafe {
-            let data: &[f32] = slice::from_raw_parts(
-                mem::transmute_copy::<*const [f32; 3], *mut f32>(&self.as_ptr()),
-                self.len() * 3
-            );
-            create_buffer(&data)
-        }
+    fn to_buffer(&self, backend: &dyn GlBackend) -> GLuint {
+        create_buffer(backend, unsafe { flatten(self) })
     }
 }
 
+/*
+ * Packed, quantized and integer data, which is read with the type of its components. Integer
+ * components are converted to floats for `float` and `vec` attributes, add an `Attribute` with
+ * `normalized(true)` to map them to 0 to 1 instead, e.g. for RGBA8 colors. Attributes that the
+ * shader declares as `int`, `uint`, `ivec` or `uvec` get the integers as they are, and `double`
+ * attributes need `f64` data.
+ */
+
+macro_rules! bufferable_components {
+    ($data:ty, $component_type:expr) => {
+        impl BufferableData for Vec<$data> {
+            fn to_buffer(&self, backend: &dyn GlBackend) -> GLuint {
+                create_buffer_from_bytes(backend, unsafe { flatten(self.as_slice()) })
+            }
+
+            fn component_type(&self) -> Option<ComponentType> {
+                Some($component_type)
+            }
+        }
+    };
+}
+
+bufferable_components!(f64, ComponentType::F64);
+bufferable_components!([f64; 2], ComponentType::F64);
+bufferable_components!([f64; 3], ComponentType::F64);
+bufferable_components!([f64; 4], ComponentType::F64);
+bufferable_components!(i8, ComponentType::I8);
+bufferable_components!([i8; 2], ComponentType::I8);
+bufferable_components!([i8; 3], ComponentType::I8);
+bufferable_components!([i8; 4], ComponentType::I8);
+bufferable_components!(u8, ComponentType::U8);
+bufferable_components!([u8; 2], ComponentType::U8);
+bufferable_components!([u8; 3], ComponentType::U8);
+bufferable_components!([u8; 4], ComponentType::U8);
+bufferable_components!(i16, ComponentType::I16);
+bufferable_components!([i16; 2], ComponentType::I16);
+bufferable_components!([i16; 3], ComponentType::I16);
+bufferable_components!([i16; 4], ComponentType::I16);
+bufferable_components!(u16, ComponentType::U16);
+bufferable_components!([u16; 2], ComponentType::U16);
+bufferable_components!([u16; 3], ComponentType::U16);
+bufferable_components!([u16; 4], ComponentType::U16);
+bufferable_components!(i32, ComponentType::I32);
+bufferable_components!([i32; 2], ComponentType::I32);
+bufferable_components!([i32; 3], ComponentType::I32);
+bufferable_components!([i32; 4], ComponentType::I32);
+bufferable_components!(u32, ComponentType::U32);
+bufferable_components!([u32; 2], ComponentType::U32);
+bufferable_components!([u32; 3], ComponentType::U32);
+bufferable_components!([u32; 4], ComponentType::U32);
+
 /**
  * Enumerate options for buffers used as the elements in gl::DrawElements().
  */
 pub trait BufferableElementsData {
-    fn to_buffer(&self, &Primitive) -> GLuint;
-    fn get_count(&self, &Primitive) -> GLint;
+    fn to_buffer(&self, _: &dyn GlBackend, _: &Primitive) -> GLuint;
+    fn get_count(&self, primitive: &Primitive) -> GLint;
 }
 
 impl BufferableElementsData for Vec<u32> {
-    fn to_buffer(&self, _: &Primitive) -> GLuint {
-        create_buffer_u32(self)
+    fn to_buffer(&self, backend: &dyn GlBackend, _: &Primitive) -> GLuint {
+        create_buffer_u32(backend, self)
     }
 
     fn get_count(&self, _: &Primitive) -> GLint {
@@ -138,16 +144,10 @@ impl BufferableElementsData for Vec<u32> {
 }
 
 impl BufferableElementsData for &'static [[u32; 2]] {
-    fn to_buffer(&self, primitive: &Primitive) -> GLuint {
+    fn to_buffer(&self, backend: &dyn GlBackend, primitive: &Primitive) -> GLuint {
         match primitive {
             &Primitive::Triangles => {
-                unsafe {
-                    let data: &[u32] = slice::from_raw_parts(
-                        mem::transmute_copy::<*const [u32; 2], *mut u32>(&self.as_ptr()),
-                        self.len() * 2
-                    );
-                    create_buffer_u32(&data)
-                }
+                create_buffer_u32(backend, unsafe { flatten(self) })
             },
             _ => panic!("Trying to set element arrays that are not the valid type")
         }
@@ -162,16 +162,10 @@ impl BufferableElementsData for &'static [[u32; 2]] {
 }
 
 impl BufferableElementsData for &'static [[u32; 3]] {
-    fn to_buffer(&self, primitive: &Primitive) -> GLuint {
+    fn to_buffer(&self, backend: &dyn GlBackend, primitive: &Primitive) -> GLuint {
         match primitive {
             &Primitive::Triangles => {
-                unsafe {
-                    let data: &[u32] = slice::from_raw_parts(
-                        mem::transmute_copy::<*const [u32; 3], *mut u32>(&self.as_ptr()),
-                        self.len() * 3
-                    );
-                    create_buffer_u32(&data)
-                }
+                create_buffer_u32(backend, unsafe { flatten(self) })
             },
             _ => panic!("Trying to set element arrays that are not the valid type")
         }
@@ -0,0 +1,601 @@
+use super::backend::{ActiveInfo, GlBackend};
+use super::gl;
+use super::gl::types::*;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// The last value that was uploaded to a uniform location.
+#[derive(PartialEq)]
+enum UniformCache {
+    Float(GLint, Vec<GLfloat>),
+    Int(GLint, Vec<GLint>),
+    Uint(GLint, Vec<GLuint>),
+    Matrix(GLint, GLint, Vec<GLfloat>)
+}
+
+/// The state that belongs to a single context. `None` means that the state is unknown, so
+/// the next call always goes through.
+#[derive(Default)]
+struct ContextState {
+    program: Option<GLuint>,
+    vao: Option<GLuint>,
+    capabilities: HashMap<GLenum, bool>,
+    blend_func: Option<(GLenum, GLenum, GLenum, GLenum)>,
+    blend_equation: Option<(GLenum, GLenum)>,
+    blend_color: Option<[GLfloat; 4]>,
+    depth_func: Option<GLenum>,
+    depth_mask: Option<bool>,
+    depth_range: Option<(GLdouble, GLdouble)>,
+    cull_face: Option<GLenum>,
+    front_face: Option<GLenum>,
+    polygon_offset: Option<(GLfloat, GLfloat)>,
+    stencil_mask: Option<GLuint>,
+    stencil_func: Option<(GLenum, GLint, GLuint)>,
+    stencil_ops: HashMap<GLenum, (GLenum, GLenum, GLenum)>,
+    viewport: Option<(GLint, GLint, GLsizei, GLsizei)>,
+    scissor: Option<(GLint, GLint, GLsizei, GLsizei)>,
+    color_mask: Option<[bool; 4]>,
+    line_width: Option<GLfloat>,
+    point_size: Option<GLfloat>
+}
+
+/// Store the new value, and return whether it's different from what was there before.
+fn changed<T: PartialEq>(slot: &mut Option<T>, value: T) -> bool {
+    if slot.as_ref() == Some(&value) {
+        return false;
+    }
+    *slot = Some(value);
+    true
+}
+
+/// A `GlBackend` that sits in front of another one, and skips calls that would set state to
+/// the value it already has, e.g. binding the same program twice in a row. `Rugl` puts one
+/// in front of every backend it's given, so draw commands can set all of their state every
+/// time they run without paying for it.
+///
+/// Binding state is tracked separately for every context, while uniform values are tracked
+/// per program, as programs are shared between contexts. Anything that changes the GL state
+/// without going through the backend must call `reset()` afterwards.
+///
+/// Texture units and their bindings aren't tracked, as there's no texture support yet, so
+/// `ActiveTexture` and `BindTexture` aren't part of the backend.
+pub struct CachingBackend {
+    backend: Rc<dyn GlBackend>,
+    current_context: Rc<Cell<usize>>,
+    contexts: RefCell<HashMap<usize, ContextState>>,
+    uniforms: RefCell<HashMap<(GLuint, GLint), UniformCache>>
+}
+
+impl CachingBackend {
+    pub fn new(backend: Rc<dyn GlBackend>, current_context: Rc<Cell<usize>>) -> CachingBackend {
+        CachingBackend {
+            backend,
+            current_context,
+            contexts: RefCell::new(HashMap::new()),
+            uniforms: RefCell::new(HashMap::new())
+        }
+    }
+
+    /// Forget everything that is known about the GL state.
+    pub fn reset(&self) {
+        self.contexts.borrow_mut().clear();
+        self.uniforms.borrow_mut().clear();
+    }
+
+    /// Forget the state of a context that was destroyed, so that a context that later gets
+    /// the same id starts out unknown.
+    pub fn forget_context(&self, context: usize) {
+        self.contexts.borrow_mut().remove(&context);
+    }
+
+    /// Update the state of the current context, and return whether the call needs to be made.
+    fn update<F>(&self, update: F) -> bool where
+        F: FnOnce(&mut ContextState) -> bool
+    {
+        let mut contexts = self.contexts.borrow_mut();
+        let state = contexts.entry(self.current_context.get()).or_default();
+        update(state)
+    }
+
+    /// Update the cached value of a uniform of the current program, and return whether it
+    /// needs to be uploaded.
+    fn update_uniform(&self, location: GLint, value: UniformCache) -> bool {
+        let program = {
+            let contexts = self.contexts.borrow();
+            contexts.get(&self.current_context.get()).and_then(|state| state.program)
+        };
+        let program = match program {
+            Some(program) => program,
+            None => return true
+        };
+        let mut uniforms = self.uniforms.borrow_mut();
+        if uniforms.get(&(program, location)) == Some(&value) {
+            return false;
+        }
+        uniforms.insert((program, location), value);
+        true
+    }
+}
+
+impl GlBackend for CachingBackend {
+    fn create_shader(&self, shader_type: GLenum) -> GLuint {
+        self.backend.create_shader(shader_type)
+    }
+
+    fn shader_source(&self, shader: GLuint, source: &str) {
+        self.backend.shader_source(shader, source)
+    }
+
+    fn compile_shader(&self, shader: GLuint) {
+        self.backend.compile_shader(shader)
+    }
+
+    fn get_shader_iv(&self, shader: GLuint, parameter: GLenum) -> GLint {
+        self.backend.get_shader_iv(shader, parameter)
+    }
+
+    fn get_shader_info_log(&self, shader: GLuint) -> String {
+        self.backend.get_shader_info_log(shader)
+    }
+
+    fn create_program(&self) -> GLuint {
+        self.backend.create_program()
+    }
+
+    fn attach_shader(&self, program: GLuint, shader: GLuint) {
+        self.backend.attach_shader(program, shader)
+    }
+
+    fn link_program(&self, program: GLuint) {
+        // Linking resets the uniforms of the program.
+        self.uniforms.borrow_mut().retain(|&(uniform_program, _), _| uniform_program != program);
+        self.backend.link_program(program)
+    }
+
+    fn get_program_iv(&self, program: GLuint, parameter: GLenum) -> GLint {
+        self.backend.get_program_iv(program, parameter)
+    }
+
+    fn get_program_info_log(&self, program: GLuint) -> String {
+        self.backend.get_program_info_log(program)
+    }
+
+    fn use_program(&self, program: GLuint) {
+        if self.update(|state| changed(&mut state.program, program)) {
+            self.backend.use_program(program)
+        }
+    }
+
+    fn get_active_attrib(&self, program: GLuint, index: GLuint) -> ActiveInfo {
+        self.backend.get_active_attrib(program, index)
+    }
+
+    fn get_active_uniform(&self, program: GLuint, index: GLuint) -> ActiveInfo {
+        self.backend.get_active_uniform(program, index)
+    }
+
+    fn get_uniform_location(&self, program: GLuint, name: &str) -> GLint {
+        self.backend.get_uniform_location(program, name)
+    }
+
+    fn uniform_fv(&self, location: GLint, components: GLint, values: &[GLfloat]) {
+        if self.update_uniform(location, UniformCache::Float(components, values.to_vec())) {
+            self.backend.uniform_fv(location, components, values)
+        }
+    }
+
+    fn uniform_iv(&self, location: GLint, components: GLint, values: &[GLint]) {
+        if self.update_uniform(location, UniformCache::Int(components, values.to_vec())) {
+            self.backend.uniform_iv(location, components, values)
+        }
+    }
+
+    fn uniform_uiv(&self, location: GLint, components: GLint, values: &[GLuint]) {
+        if self.update_uniform(location, UniformCache::Uint(components, values.to_vec())) {
+            self.backend.uniform_uiv(location, components, values)
+        }
+    }
+
+    fn uniform_matrix_fv(&self, location: GLint, columns: GLint, rows: GLint, values: &[GLfloat]) {
+        let value = UniformCache::Matrix(columns, rows, values.to_vec());
+        if self.update_uniform(location, value) {
+            self.backend.uniform_matrix_fv(location, columns, rows, values)
+        }
+    }
+
+    fn gen_buffer(&self) -> GLuint {
+        self.backend.gen_buffer()
+    }
+
+    fn bind_buffer(&self, target: GLenum, buffer: GLuint) {
+        // The element array binding is part of the vertex array state, so buffer bindings
+        // aren't worth tracking.
+        self.backend.bind_buffer(target, buffer)
+    }
+
+    fn buffer_data(&self, target: GLenum, data: &[u8], usage: GLenum) {
+        self.backend.buffer_data(target, data, usage)
+    }
+
+    fn gen_vertex_array(&self) -> GLuint {
+        self.backend.gen_vertex_array()
+    }
+
+    fn delete_vertex_array(&self, vao: GLuint) {
+        // The GL binds 0 in place of a deleted vertex array, and the name can be reused.
+        self.update(|state| {
+            if state.vao == Some(vao) {
+                state.vao = None;
+            }
+            false
+        });
+        self.backend.delete_vertex_array(vao)
+    }
+
+    fn bind_vertex_array(&self, vao: GLuint) {
+        if self.update(|state| changed(&mut state.vao, vao)) {
+            self.backend.bind_vertex_array(vao)
+        }
+    }
+
+    fn enable_vertex_attrib_array(&self, index: GLuint) {
+        self.backend.enable_vertex_attrib_array(index)
+    }
+
+    fn vertex_attrib_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        data_type: GLenum,
+        normalized: bool,
+        stride: GLsizei,
+        offset: usize
+    ) {
+        self.backend.vertex_attrib_pointer(index, size, data_type, normalized, stride, offset)
+    }
+
+    fn vertex_attrib_i_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        data_type: GLenum,
+        stride: GLsizei,
+        offset: usize
+    ) {
+        self.backend.vertex_attrib_i_pointer(index, size, data_type, stride, offset)
+    }
+
+    fn vertex_attrib_l_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        data_type: GLenum,
+        stride: GLsizei,
+        offset: usize
+    ) {
+        self.backend.vertex_attrib_l_pointer(index, size, data_type, stride, offset)
+    }
+
+    fn vertex_attrib_divisor(&self, index: GLuint, divisor: GLuint) {
+        self.backend.vertex_attrib_divisor(index, divisor)
+    }
+
+    fn vertex_attrib_4f(&self, index: GLuint, x: GLfloat, y: GLfloat, z: GLfloat, w: GLfloat) {
+        self.backend.vertex_attrib_4f(index, x, y, z, w)
+    }
+
+    fn vertex_attrib_i4i(&self, index: GLuint, x: GLint, y: GLint, z: GLint, w: GLint) {
+        self.backend.vertex_attrib_i4i(index, x, y, z, w)
+    }
+
+    fn vertex_attrib_i4ui(&self, index: GLuint, x: GLuint, y: GLuint, z: GLuint, w: GLuint) {
+        self.backend.vertex_attrib_i4ui(index, x, y, z, w)
+    }
+
+    fn gen_framebuffer(&self) -> GLuint {
+        self.backend.gen_framebuffer()
+    }
+
+    fn bind_framebuffer(&self, target: GLenum, framebuffer: GLuint) {
+        self.backend.bind_framebuffer(target, framebuffer)
+    }
+
+    fn gen_renderbuffer(&self) -> GLuint {
+        self.backend.gen_renderbuffer()
+    }
+
+    fn bind_renderbuffer(&self, renderbuffer: GLuint) {
+        self.backend.bind_renderbuffer(renderbuffer)
+    }
+
+    fn renderbuffer_storage(&self, internal_format: GLenum, width: GLsizei, height: GLsizei) {
+        self.backend.renderbuffer_storage(internal_format, width, height)
+    }
+
+    fn framebuffer_renderbuffer(&self, attachment: GLenum, renderbuffer: GLuint) {
+        self.backend.framebuffer_renderbuffer(attachment, renderbuffer)
+    }
+
+    fn delete_framebuffer(&self, framebuffer: GLuint) {
+        self.backend.delete_framebuffer(framebuffer)
+    }
+
+    fn delete_renderbuffer(&self, renderbuffer: GLuint) {
+        self.backend.delete_renderbuffer(renderbuffer)
+    }
+
+    fn check_framebuffer_status(&self, target: GLenum) -> GLenum {
+        self.backend.check_framebuffer_status(target)
+    }
+
+    fn clear_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+        self.backend.clear_color(red, green, blue, alpha)
+    }
+
+    fn clear_depth(&self, depth: GLdouble) {
+        self.backend.clear_depth(depth)
+    }
+
+    fn clear_stencil(&self, stencil: GLint) {
+        self.backend.clear_stencil(stencil)
+    }
+
+    fn clear(&self, mask: GLbitfield) {
+        self.backend.clear(mask)
+    }
+
+    fn enable(&self, capability: GLenum) {
+        let changed = self.update(|state| {
+            state.capabilities.insert(capability, true) != Some(true)
+        });
+        if changed {
+            self.backend.enable(capability)
+        }
+    }
+
+    fn disable(&self, capability: GLenum) {
+        let changed = self.update(|state| {
+            state.capabilities.insert(capability, false) != Some(false)
+        });
+        if changed {
+            self.backend.disable(capability)
+        }
+    }
+
+    fn blend_func_separate(
+        &self,
+        src_rgb: GLenum,
+        dst_rgb: GLenum,
+        src_alpha: GLenum,
+        dst_alpha: GLenum
+    ) {
+        let value = (src_rgb, dst_rgb, src_alpha, dst_alpha);
+        if self.update(|state| changed(&mut state.blend_func, value)) {
+            self.backend.blend_func_separate(src_rgb, dst_rgb, src_alpha, dst_alpha)
+        }
+    }
+
+    fn blend_equation_separate(&self, mode_rgb: GLenum, mode_alpha: GLenum) {
+        if self.update(|state| changed(&mut state.blend_equation, (mode_rgb, mode_alpha))) {
+            self.backend.blend_equation_separate(mode_rgb, mode_alpha)
+        }
+    }
+
+    fn blend_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+        if self.update(|state| changed(&mut state.blend_color, [red, green, blue, alpha])) {
+            self.backend.blend_color(red, green, blue, alpha)
+        }
+    }
+
+    fn depth_func(&self, func: GLenum) {
+        if self.update(|state| changed(&mut state.depth_func, func)) {
+            self.backend.depth_func(func)
+        }
+    }
+
+    fn depth_mask(&self, mask: bool) {
+        if self.update(|state| changed(&mut state.depth_mask, mask)) {
+            self.backend.depth_mask(mask)
+        }
+    }
+
+    fn depth_range(&self, near: GLdouble, far: GLdouble) {
+        if self.update(|state| changed(&mut state.depth_range, (near, far))) {
+            self.backend.depth_range(near, far)
+        }
+    }
+
+    fn cull_face(&self, mode: GLenum) {
+        if self.update(|state| changed(&mut state.cull_face, mode)) {
+            self.backend.cull_face(mode)
+        }
+    }
+
+    fn front_face(&self, mode: GLenum) {
+        if self.update(|state| changed(&mut state.front_face, mode)) {
+            self.backend.front_face(mode)
+        }
+    }
+
+    fn polygon_offset(&self, factor: GLfloat, units: GLfloat) {
+        if self.update(|state| changed(&mut state.polygon_offset, (factor, units))) {
+            self.backend.polygon_offset(factor, units)
+        }
+    }
+
+    fn stencil_mask(&self, mask: GLuint) {
+        if self.update(|state| changed(&mut state.stencil_mask, mask)) {
+            self.backend.stencil_mask(mask)
+        }
+    }
+
+    fn stencil_func(&self, func: GLenum, reference: GLint, mask: GLuint) {
+        if self.update(|state| changed(&mut state.stencil_func, (func, reference, mask))) {
+            self.backend.stencil_func(func, reference, mask)
+        }
+    }
+
+    fn stencil_op_separate(&self, face: GLenum, fail: GLenum, zfail: GLenum, zpass: GLenum) {
+        let changed = self.update(|state| {
+            let ops = (fail, zfail, zpass);
+            match face {
+                gl::FRONT | gl::BACK => state.stencil_ops.insert(face, ops) != Some(ops),
+                _ => {
+                    state.stencil_ops.insert(gl::FRONT, ops);
+                    state.stencil_ops.insert(gl::BACK, ops);
+                    true
+                }
+            }
+        });
+        if changed {
+            self.backend.stencil_op_separate(face, fail, zfail, zpass)
+        }
+    }
+
+    fn viewport(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+        if self.update(|state| changed(&mut state.viewport, (x, y, width, height))) {
+            self.backend.viewport(x, y, width, height)
+        }
+    }
+
+    fn scissor(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+        if self.update(|state| changed(&mut state.scissor, (x, y, width, height))) {
+            self.backend.scissor(x, y, width, height)
+        }
+    }
+
+    fn color_mask(&self, red: bool, green: bool, blue: bool, alpha: bool) {
+        if self.update(|state| changed(&mut state.color_mask, [red, green, blue, alpha])) {
+            self.backend.color_mask(red, green, blue, alpha)
+        }
+    }
+
+    fn line_width(&self, width: GLfloat) {
+        if self.update(|state| changed(&mut state.line_width, width)) {
+            self.backend.line_width(width)
+        }
+    }
+
+    fn point_size(&self, size: GLfloat) {
+        if self.update(|state| changed(&mut state.point_size, size)) {
+            self.backend.point_size(size)
+        }
+    }
+
+    fn draw_arrays(&self, mode: GLenum, first: GLint, count: GLsizei) {
+        self.backend.draw_arrays(mode, first, count)
+    }
+
+    fn draw_elements(&self, mode: GLenum, count: GLsizei, data_type: GLenum, offset: usize) {
+        self.backend.draw_elements(mode, count, data_type, offset)
+    }
+
+    fn draw_arrays_instanced(&self, mode: GLenum, first: GLint, count: GLsizei, instances: GLsizei) {
+        self.backend.draw_arrays_instanced(mode, first, count, instances)
+    }
+
+    fn draw_elements_instanced(
+        &self,
+        mode: GLenum,
+        count: GLsizei,
+        data_type: GLenum,
+        offset: usize,
+        instances: GLsizei
+    ) {
+        self.backend.draw_elements_instanced(mode, count, data_type, offset, instances)
+    }
+
+    fn finish(&self) {
+        self.backend.finish()
+    }
+
+    fn read_pixels(
+        &self,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: GLenum,
+        pixel_type: GLenum,
+        data: &mut [u8]
+    ) {
+        self.backend.read_pixels(x, y, width, height, format, pixel_type, data)
+    }
+
+    fn get_integer_v(&self, parameter: GLenum) -> GLint {
+        self.backend.get_integer_v(parameter)
+    }
+
+    fn get_float_v(&self, parameter: GLenum, values: &mut [GLfloat]) {
+        self.backend.get_float_v(parameter, values)
+    }
+
+    fn get_error(&self) -> GLenum {
+        self.backend.get_error()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::recording_backend::RecordingBackend;
+
+    #[test]
+    fn redundant_calls_are_skipped() {
+        let recording = Rc::new(RecordingBackend::new());
+        let backend = CachingBackend::new(recording.clone(), Rc::new(Cell::new(0)));
+
+        backend.use_program(1);
+        backend.enable(gl::DEPTH_TEST);
+        backend.uniform_fv(0, 1, &[0.5]);
+        backend.use_program(1);
+        backend.enable(gl::DEPTH_TEST);
+        backend.uniform_fv(0, 1, &[0.5]);
+        backend.uniform_fv(0, 1, &[0.25]);
+
+        assert_eq!(recording.calls(), vec![
+            "UseProgram(1)".to_string(),
+            format!("Enable({})", gl::DEPTH_TEST),
+            "Uniform1fv(0, [0.5])".to_string(),
+            "Uniform1fv(0, [0.25])".to_string()
+        ]);
+    }
+
+    #[test]
+    fn every_context_has_its_own_state() {
+        let recording = Rc::new(RecordingBackend::new());
+        let current_context = Rc::new(Cell::new(0));
+        let backend = CachingBackend::new(recording.clone(), current_context.clone());
+
+        backend.bind_vertex_array(1);
+        current_context.set(1);
+        backend.bind_vertex_array(1);
+        backend.reset();
+        backend.bind_vertex_array(1);
+
+        assert_eq!(recording.calls().len(), 3);
+    }
+
+    #[test]
+    fn deleted_and_forgotten_state_is_set_again() {
+        let recording = Rc::new(RecordingBackend::new());
+        let current_context = Rc::new(Cell::new(0));
+        let backend = CachingBackend::new(recording.clone(), current_context.clone());
+
+        backend.bind_vertex_array(1);
+        backend.use_program(2);
+        backend.delete_vertex_array(1);
+        backend.bind_vertex_array(1);
+        backend.forget_context(0);
+        backend.use_program(2);
+
+        assert_eq!(recording.calls(), vec![
+            "BindVertexArray(1)".to_string(),
+            "UseProgram(2)".to_string(),
+            "DeleteVertexArrays(1)".to_string(),
+            "BindVertexArray(1)".to_string(),
+            "UseProgram(2)".to_string()
+        ]);
+    }
+}
@@ -1,6 +1,8 @@
+use super::backend::GlBackend;
 use super::gl::types::*;
 use super::gl;
 use super::rugl;
+use std::rc::Rc;
 
 #[macro_export]
 macro_rules! rugl {
@@ -19,38 +21,41 @@ macro_rules! rugl {
 ///     #[macro_use]
 ///     extern crate rugl;
 ///
-///     fn main() {
-///         // Use rugl::init() for your code.
-///         let rugl = rugl::init_headless();
+///     use rugl::recording_backend::RecordingBackend;
+///     use std::rc::Rc;
 ///
-///         // Create a clear command that clears the color, depth and stencil.
-///         let clear_all = rugl!(rugl.clear, {
-///             color => [0.0, 0.0, 0.0, 1.0],
-///             depth => 1.0,
-///             stencil => 0
-///         });
+///     // Use rugl::init() for your code.
+///     let rugl = rugl::init_with_backend(Rc::new(RecordingBackend::new()));
 ///
-///         // Create a clear command that clears only the color.
-///         let clear_only_color = rugl!(rugl.clear, {
-///             color => [0.0, 0.0, 0.0, 1.0]
-///         });
-///     }
+///     // Create a clear command that clears the color, depth and stencil.
+///     let clear_all = rugl!(rugl.clear, {
+///         color => [0.0, 0.0, 0.0, 1.0],
+///         depth => 1.0,
+///         stencil => 0
+///     });
+///
+///     // Create a clear command that clears only the color.
+///     let clear_only_color = rugl!(rugl.clear, {
+///         color => [0.0, 0.0, 0.0, 1.0]
+///     });
 ///
 /// Then run `clear_all()` or `clear_only_color()` to run the command. The `rugl!` macro quickly
 /// sets the values and returns a closure that can perform the action. While this is the preferred
 /// API, the `Clear` struct can be used by itself, especially if the clear command needs to be
 /// mutated over time.
 ///
-///     use rugl;
-///     let rugl = rugl::init_headless();
+///     use rugl::recording_backend::RecordingBackend;
+///     use std::rc::Rc;
+///
+///     let rugl = rugl::init_with_backend(Rc::new(RecordingBackend::new()));
 ///
 ///     let mut clear = rugl.clear();
 ///     clear.color = Some([0.0, 0.0, 0.0, 1.0]);
-///     // clear.execute();
+///     clear.execute();
 ///
 ///     // The returned function assumes ownership of the Clear struct.
 ///     let clear_black = clear.make_execute_fn();
-///     // clear_black();
+///     clear_black();
 ///
 pub struct Clear {
     /// Sets the clear color
@@ -58,90 +63,112 @@ pub struct Clear {
     /// Sets the clear depth value
     pub depth: Option<f64>,
     /// Sets the clear stencil value
-    pub stencil: Option<i32>
+    pub stencil: Option<i32>,
+    backend: Rc<dyn GlBackend>
 }
 
 impl Clear {
     /// Create a new clear object.
-    pub fn new() -> Clear {
+    pub fn new(backend: Rc<dyn GlBackend>) -> Clear {
         Clear {
             color: None,
             depth: None,
-            stencil: None
+            stencil: None,
+            backend
         }
     }
 
     /// Execute the glClear with the set values.
     pub fn execute(&self) {
-        unsafe {
-            let mut clear_bits: GLenum = 0;
-            match self.color {
-                Some(color) => {
-                    clear_bits = clear_bits | gl::COLOR_BUFFER_BIT;
-                    gl::ClearColor(color[0], color[1], color[2], color[3]);
-                    log_draw!("gl::ClearColor({}, {}, {}, {})", color[0], color[1], color[2], color[3]);
-                },
-                None => {},
-            };
-            match self.depth {
-                Some(depth) => {
-                    clear_bits = clear_bits | gl::DEPTH_BUFFER_BIT;
-                    gl::ClearDepth(depth);
-                    log_draw!("gl::ClearDepth({})", depth);
-                }
-                None => {}
-            };
-            match self.stencil {
-                Some(stencil) => {
-                    clear_bits = clear_bits | gl::STENCIL_BUFFER_BIT;
-                    gl::ClearStencil(stencil);
-                    log_draw!("gl::ClearStencil({})", depth);
-                },
-                None => {}
-            };
-            if clear_bits != 0 {
-                gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
-            }
+        let mut clear_bits: GLbitfield = 0;
+        if let Some(color) = self.color {
+            clear_bits |= gl::COLOR_BUFFER_BIT;
+            self.backend.clear_color(color[0], color[1], color[2], color[3]);
+            log_draw!("gl::ClearColor({}, {}, {}, {})", color[0], color[1], color[2], color[3]);
+            // Masked out channels aren't cleared either.
+            self.backend.color_mask(true, true, true, true);
+            log_draw!("gl::ColorMask(true, true, true, true)");
+        };
+        if let Some(depth) = self.depth {
+            clear_bits |= gl::DEPTH_BUFFER_BIT;
+            self.backend.clear_depth(depth);
+            log_draw!("gl::ClearDepth({})", depth);
+            // A draw command may have turned off depth writes, which also stops clearing.
+            self.backend.depth_mask(true);
+            log_draw!("gl::DepthMask(true)");
+        };
+        if let Some(stencil) = self.stencil {
+            clear_bits |= gl::STENCIL_BUFFER_BIT;
+            self.backend.clear_stencil(stencil);
+            log_draw!("gl::ClearStencil({})", stencil);
+            // Only the bits in the stencil write mask are cleared.
+            self.backend.stencil_mask(0xff);
+            log_draw!("gl::StencilMask(0xff)");
+        };
+        if clear_bits != 0 {
+            // Clear the whole render target, even if the last command used a scissor.
+            log_draw!("gl::Disable(gl::SCISSOR_TEST)");
+            self.backend.disable(gl::SCISSOR_TEST);
+            log_draw!("gl::Clear({})", clear_bits);
+            self.backend.clear(clear_bits);
         }
     }
 
     /// Consume the struct and get a closure over `execute()`.
-    pub fn make_execute_fn(self) -> Box<Fn()> {
+    pub fn make_execute_fn(self) -> Box<dyn Fn()> {
         Box::new(move || self.execute())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::rugl::init_headless;
+    use super::rugl::init_with_backend;
+    use super::super::gl;
+    use super::super::recording_backend::RecordingBackend;
+    use std::rc::Rc;
+
     #[test]
     fn clear_on_a_macro() {
-        let rugl = init_headless();
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
 
-        #[allow(unused_variables)]
         let clear1 = rugl!(rugl.clear, {
             color => [0.3, 0.2, 0.3, 1.0],
             depth => 1.0,
             stencil => 0
         });
 
-        #[allow(unused_variables)]
         let clear2 = rugl!(rugl.clear, {
             color => [0.3, 0.2, 0.3, 1.0],
             stencil => 0
         });
 
-        #[allow(unused_variables)]
-        let clear2 = rugl!(rugl.clear, {
+        let clear3 = rugl!(rugl.clear, {
             color => [0.3, 0.2, 0.3, 1.0]
         });
+
+        clear1();
+        clear2();
+        clear3();
+
+        let clears: Vec<String> = backend.calls().into_iter()
+            .filter(|call| call.starts_with("Clear("))
+            .collect();
+        assert_eq!(clears, vec![
+            format!(
+                "Clear({})",
+                gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT | gl::STENCIL_BUFFER_BIT
+            ),
+            format!("Clear({})", gl::COLOR_BUFFER_BIT | gl::STENCIL_BUFFER_BIT),
+            format!("Clear({})", gl::COLOR_BUFFER_BIT)
+        ]);
     }
 
     #[test]
     fn clear_by_object() {
-        let rugl = init_headless();
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
 
-        #[allow(unused_variables)]
         let clear = {
             let mut clear = rugl.clear();
             clear.color = Some([0.3, 0.2, 0.3, 1.0]);
@@ -149,7 +176,47 @@ mod tests {
             clear.stencil = Some(0);
             clear.make_execute_fn()
         };
-        // clear();
+        clear();
+
+        assert_eq!(
+            backend.calls().last(),
+            Some(&format!(
+                "Clear({})",
+                gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT | gl::STENCIL_BUFFER_BIT
+            ))
+        );
     }
 
+    #[test]
+    fn clear_only_sets_the_requested_bits() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        let clear_color_and_stencil = rugl!(rugl.clear, {
+            color => [0.5, 0.25, 0.5, 1.0],
+            stencil => 1
+        });
+        backend.clear_calls();
+        clear_color_and_stencil();
+
+        assert_eq!(backend.calls(), vec![
+            "ClearColor(0.5, 0.25, 0.5, 1.0)".to_string(),
+            "ColorMask(true, true, true, true)".to_string(),
+            "ClearStencil(1)".to_string(),
+            "StencilMask(255)".to_string(),
+            format!("Disable({})", gl::SCISSOR_TEST),
+            format!("Clear({})", gl::COLOR_BUFFER_BIT | gl::STENCIL_BUFFER_BIT)
+        ]);
+    }
+
+    #[test]
+    fn empty_clear_does_nothing() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        backend.clear_calls();
+        rugl.clear().execute();
+
+        assert!(backend.calls().is_empty());
+    }
 }
@@ -0,0 +1,132 @@
+extern crate time;
+
+/// Where the time in the `Environment` comes from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Clock {
+    /// Wall clock time, this is the default.
+    Realtime,
+    /// Every tick advances the time by exactly this many seconds, no matter how long the frame
+    /// actually took. The first tick is at time 0. Use this to get reproducible animations when
+    /// rendering offline or comparing against golden images.
+    FixedStep(f64),
+    /// Time only moves when `Rugl::advance_clock()` is called.
+    Manual
+}
+
+/// The maximum number of fixed updates that run in a single frame. If the frame took longer
+/// than this, the remaining time is dropped so that a slow frame can't snowball into slower
+/// and slower frames.
+const MAX_FIXED_UPDATES: u32 = 10;
+
+/// Keeps track of the current time according to a `Clock`.
+pub struct Timer {
+    clock: Clock,
+    last_instant: f64,
+    time: f64,
+    started: bool
+}
+
+impl Timer {
+    pub fn new(clock: Clock) -> Timer {
+        Timer {
+            clock,
+            last_instant: time::precise_time_s(),
+            time: 0.0,
+            started: false
+        }
+    }
+
+    /// Switch to a different clock, the time carries on from where the old clock left off.
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = clock;
+    }
+
+    pub fn advance(&mut self, seconds: f64) {
+        self.time += seconds;
+    }
+
+    /// Move on to the next tick, and return the time of that tick in seconds.
+    pub fn tick(&mut self) -> f64 {
+        let now = time::precise_time_s();
+        match self.clock {
+            Clock::Realtime => self.time += now - self.last_instant,
+            Clock::FixedStep(step) => {
+                if self.started {
+                    self.time += step;
+                }
+            },
+            Clock::Manual => {}
+        };
+        self.last_instant = now;
+        self.started = true;
+        self.time
+    }
+}
+
+/// Collects the time between frames, and hands it out in fixed steps.
+pub struct Accumulator {
+    pub step: f64,
+    accumulated: f64
+}
+
+impl Accumulator {
+    pub fn new(step: f64) -> Accumulator {
+        Accumulator {
+            step,
+            accumulated: 0.0
+        }
+    }
+
+    /// Add the time that has passed since the last frame, and return how many fixed steps
+    /// should run for this frame.
+    pub fn add(&mut self, delta_time: f64) -> u32 {
+        self.accumulated += delta_time;
+
+        let mut steps = 0;
+        while self.accumulated >= self.step && steps < MAX_FIXED_UPDATES {
+            self.accumulated -= self.step;
+            steps += 1;
+        }
+        if self.accumulated >= self.step {
+            self.accumulated %= self.step;
+        }
+        steps
+    }
+
+    /// How far the leftover time is into the next step, from 0 to 1. This is used to
+    /// interpolate between the last two fixed updates when drawing.
+    pub fn alpha(&self) -> f64 {
+        self.accumulated / self.step
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_step_ignores_the_wall_clock() {
+        let mut timer = Timer::new(Clock::FixedStep(0.5));
+        assert_eq!(timer.tick(), 0.0);
+        assert_eq!(timer.tick(), 0.5);
+        assert_eq!(timer.tick(), 1.0);
+
+        timer.set_clock(Clock::Manual);
+        assert_eq!(timer.tick(), 1.0);
+        timer.advance(2.0);
+        assert_eq!(timer.tick(), 3.0);
+    }
+
+    #[test]
+    fn accumulator_keeps_the_leftover_time() {
+        let mut accumulator = Accumulator::new(0.25);
+        assert_eq!(accumulator.add(0.625), 2);
+        assert_eq!(accumulator.alpha(), 0.5);
+        assert_eq!(accumulator.add(0.125), 1);
+        assert_eq!(accumulator.alpha(), 0.0);
+
+        // Long frames are capped, rather than trying to catch up.
+        assert_eq!(accumulator.add(100.0), MAX_FIXED_UPDATES);
+        assert_eq!(accumulator.alpha(), 0.0);
+    }
+}
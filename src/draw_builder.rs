@@ -1,71 +1,233 @@
+use super::attributes::{Attribute, IntoAttribute, VertexBuffer};
+use super::backend::GlBackend;
 use super::gl_helpers;
-use super::buffers::{BufferableData, BufferableElementsData};
+use super::gl_helpers::AttributeInfo;
+use super::buffers::BufferableElementsData;
 use super::gl::types::*;
 use super::gl;
 use super::rugl;
 use super::uniforms::UniformValue;
+use super::state::{Blend, Cull, Depth, Dynamic, FrontFace, Limits, PolygonOffset, Rect, Stencil};
+use super::state::PropsFunction;
+use super::scope::ScopeStack;
 use super::Primitive;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
+use std::rc::Rc;
 use std::string;
 use std::ptr;
 
-pub struct DrawConfig<> {
+/// Computes the value of a uniform when the command runs.
+pub type UniformSetter<P> = Box<dyn Fn(&rugl::Environment, &P) -> Box<dyn UniformValue>>;
+
+/// Picks the buffer that an attribute reads from when the command runs.
+pub type AttributeSource<P> = Box<dyn Fn(&rugl::Environment, &P) -> GLuint>;
+
+/// Sets a uniform of the linked program for the environment that the command runs in.
+type SetUniform = Box<dyn Fn(&rugl::Environment)>;
+
+/// Computes the value of a uniform from the environment when a draw command runs.
+pub type UniformFn = Box<dyn Fn(&rugl::Environment) -> Box<dyn UniformValue>>;
+
+pub struct DrawConfig<P = ()> {
     pub vert: Option<&'static str>,
     pub frag: Option<&'static str>,
-    pub attributes: Vec<(String, GLuint)>,
+    pub attributes: Vec<(String, Attribute)>,
+    pub attribute_sources: Vec<(String, AttributeSource<P>)>,
+    pub attribute_constants: HashMap<String, [f32; 4]>,
     pub elements: Option<GLuint>,
-    pub uniform_setters: HashMap<
-        String,
-        Box<Fn(&rugl::Environment) -> Box<UniformValue>>
-    >,
+    pub uniform_setters: HashMap<String, UniformFn>,
+    pub uniform_prop_setters: HashMap<String, UniformSetter<P>>,
     pub primitive: Primitive,
-    pub count: i32
+    pub count: Dynamic<i32, P>,
+    pub offset: Dynamic<i32, P>,
+    pub instances: Option<Dynamic<i32, P>>,
+    pub divisors: HashMap<String, GLuint>,
+    pub depth: Option<Depth>,
+    pub blend: Option<Blend>,
+    pub cull: Option<Cull>,
+    pub front_face: Option<FrontFace>,
+    pub polygon_offset: Option<PolygonOffset>,
+    pub stencil: Option<Stencil>,
+    pub viewport: Option<Dynamic<Rect, P>>,
+    pub scissor: Option<Dynamic<Rect, P>>,
+    pub color_mask: Option<[bool; 4]>,
+    pub line_width: Option<f32>,
+    pub point_size: Option<f32>,
+    pub program_point_size: Option<bool>
 }
 
-pub struct DrawBuilder {
-    pub config: DrawConfig
+/// The vertex array objects that draw commands created in each context. They aren't shared
+/// between contexts, so `Rugl` deletes them when the window of their context closes.
+pub type VertexArrays = Rc<RefCell<HashMap<usize, Vec<GLuint>>>>;
+
+/// Builds a draw command. Commands are called with a props value of type `P`, which the
+/// uniforms, attributes, count, offset, viewport and scissor can be computed from, so that
+/// one command can draw many different objects. `Rugl::draw()` builds commands without
+/// props, and `Rugl::draw_with_props()` builds commands with them.
+pub struct DrawBuilder<P = ()> {
+    pub config: DrawConfig<P>,
+    backend: Rc<dyn GlBackend>,
+    current_context: Rc<Cell<usize>>,
+    vertex_arrays: VertexArrays,
+    limits: Limits,
+    scopes: ScopeStack
+}
+
+/// A finalized draw command, which is run with `call()`.
+///
+///     use rugl::recording_backend::RecordingBackend;
+///     use std::rc::Rc;
+///
+///     struct Props {
+///         color: [f32; 3]
+///     }
+///
+///     let rugl = rugl::init_with_backend(Rc::new(RecordingBackend::new()));
+///     let draw = rugl.draw_with_props::<Props>()
+///         .vert("in vec2 position; void main() { gl_Position = vec4(position, 0, 1); }")
+///         .frag("uniform vec3 color; out vec4 c; void main() { c = vec4(color, 1); }")
+///         .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+///         .uniform_prop("color", Box::new(|_, props: &Props| Box::new(props.color)))
+///         .count(3)
+///         .finalize_command();
+///
+///     let environment = rugl::Environment::new(100, 100);
+///     draw.call(&environment, &Props { color: [1.0, 0.0, 0.0] });
+///     draw.call(&environment, &Props { color: [0.0, 0.0, 1.0] });
+///
+pub struct DrawCommand<P = ()> {
+    bind: Box<dyn Fn(&rugl::Environment)>,
+    draw: PropsFunction<P, ()>
 }
 
-impl DrawBuilder {
-    pub fn new() -> DrawBuilder {
+impl<P> DrawCommand<P> {
+    pub fn call(&self, environment: &rugl::Environment, props: &P) {
+        (self.bind)(environment);
+        (self.draw)(environment, props);
+    }
+
+    /// Draw once for every item of `props`. The program, vertex array and render state are
+    /// only bound once, and then only the uniforms and attributes that come from the props
+    /// are updated between the draws.
+    pub fn batch(&self, environment: &rugl::Environment, props: &[P]) {
+        if props.is_empty() {
+            return;
+        }
+        (self.bind)(environment);
+        for item in props.iter() {
+            (self.draw)(environment, item);
+        }
+    }
+}
+
+impl<P: 'static> DrawBuilder<P> {
+    /// The `current_context` is updated by `Rugl` whenever it switches between the contexts
+    /// of its windows, every vertex array object is added to `vertex_arrays`, the `limits` are
+    /// used to validate the settings, and the `scopes` fill in the settings that the command
+    /// leaves out.
+    pub fn new(
+        backend: Rc<dyn GlBackend>,
+        current_context: Rc<Cell<usize>>,
+        vertex_arrays: VertexArrays,
+        limits: Limits,
+        scopes: ScopeStack
+    ) -> DrawBuilder<P> {
         DrawBuilder {
+            backend,
+            current_context,
+            vertex_arrays,
+            limits,
+            scopes,
             config: DrawConfig {
                 vert: None,
                 frag: None,
                 attributes: Vec::new(),
+                attribute_sources: Vec::new(),
+                attribute_constants: HashMap::new(),
                 elements: None,
                 uniform_setters: HashMap::new(),
+                uniform_prop_setters: HashMap::new(),
                 primitive: Primitive::Triangles,
-                count: 0
+                count: Dynamic::Static(0),
+                offset: Dynamic::Static(0),
+                instances: None,
+                divisors: HashMap::new(),
+                depth: None,
+                blend: None,
+                cull: None,
+                front_face: None,
+                polygon_offset: None,
+                stencil: None,
+                viewport: None,
+                scissor: None,
+                color_mask: None,
+                line_width: None,
+                point_size: None,
+                program_point_size: None
             }
         }
     }
 
-    pub fn vert(mut self, source: &'static str) -> DrawBuilder {
+    pub fn vert(mut self, source: &'static str) -> DrawBuilder<P> {
         self.config.vert = Some(source);
         self
     }
 
-    pub fn frag(mut self, source: &'static str) -> DrawBuilder {
+    pub fn frag(mut self, source: &'static str) -> DrawBuilder<P> {
         self.config.frag = Some(source);
         self
     }
 
-    pub fn uniform(mut self, name: &str, setter: Box<Fn(&rugl::Environment) -> Box<UniformValue>>) -> DrawBuilder {
+    pub fn uniform(mut self, name: &str, setter: UniformFn) -> DrawBuilder<P> {
         self.config.uniform_setters.insert(name.to_string(), setter);
         self
     }
 
-    pub fn attribute(
-        mut self, name: &str, vertices: &BufferableData
-    ) -> DrawBuilder {
+    /// Set a uniform from the props that the command is called with.
+    pub fn uniform_prop(mut self, name: &str, setter: UniformSetter<P>) -> DrawBuilder<P> {
+        self.config.uniform_prop_setters.insert(name.to_string(), setter);
+        self
+    }
+
+    /// Read an attribute from vertex data, which is uploaded into a new buffer, or from an
+    /// `Attribute` that describes the layout of an existing buffer.
+    pub fn attribute<A: IntoAttribute>(
+        mut self, name: &str, source: A
+    ) -> DrawBuilder<P> {
         self.config.attributes.push(
-            (name.to_string(), vertices.to_buffer())
+            (name.to_string(), source.into_attribute(&*self.backend))
         );
         self
     }
 
-    pub fn primitive(mut self, primitive: Primitive) -> DrawBuilder {
+    /// Read every field of interleaved vertices with the attribute of the same name. Fields
+    /// can still be overridden with `.attribute()` afterwards, e.g. to normalize colors.
+    pub fn vertex_buffer(mut self, vertices: &VertexBuffer) -> DrawBuilder<P> {
+        for field in vertices.fields.iter() {
+            self.config.attributes.push((field.name.to_string(), vertices.attribute(field.name)));
+        }
+        self
+    }
+
+    /// Read an attribute from a buffer chosen by the props, e.g. one created with
+    /// `Rugl::buffer()` for every mesh that the command draws.
+    pub fn attribute_prop(mut self, name: &str, source: AttributeSource<P>) -> DrawBuilder<P> {
+        self.config.attribute_sources.push((name.to_string(), source));
+        self
+    }
+
+    /// Give every vertex the same value for an attribute, so that shaders can be shared with
+    /// meshes that don't have it, e.g. a color. Components that the attribute doesn't have are
+    /// ignored, and `int` or `uint` attributes get the values truncated to integers. `double`
+    /// attributes can't have constants. The constant is only used when the attribute isn't
+    /// given a buffer.
+    pub fn attribute_constant(mut self, name: &str, value: [f32; 4]) -> DrawBuilder<P> {
+        self.config.attribute_constants.insert(name.to_string(), value);
+        self
+    }
+
+    pub fn primitive(mut self, primitive: Primitive) -> DrawBuilder<P> {
         match self.config.elements {
             Some(_) => panic!(".primitive() must be called before .elements() in order to properly create a buffer from a borrowed value."),
             None => { self.config.primitive = primitive }
@@ -74,146 +236,1085 @@ impl DrawBuilder {
     }
 
     pub fn elements(
-        mut self, elements: &BufferableElementsData
-    ) -> DrawBuilder {
-        self.config.elements = Some(elements.to_buffer(&self.config.primitive));
-        self.config.count = elements.get_count(&self.config.primitive);
+        mut self, elements: &dyn BufferableElementsData
+    ) -> DrawBuilder<P> {
+        self.config.elements = Some(elements.to_buffer(&*self.backend, &self.config.primitive));
+        self.config.count = Dynamic::Static(elements.get_count(&self.config.primitive));
+        self
+    }
+
+    pub fn count(mut self, count: i32) -> DrawBuilder<P> {
+        self.config.count = Dynamic::Static(count);
+        self
+    }
+
+    /// Compute how many vertices or elements to draw from the props.
+    pub fn count_prop(mut self, count: PropsFunction<P, i32>) -> DrawBuilder<P> {
+        self.config.count = Dynamic::Props(count);
+        self
+    }
+
+    /// The first vertex to draw, or the first element when drawing with `.elements()`.
+    pub fn offset(mut self, offset: i32) -> DrawBuilder<P> {
+        self.config.offset = Dynamic::Static(offset);
+        self
+    }
+
+    /// Compute the first vertex or element to draw from the props.
+    pub fn offset_prop(mut self, offset: PropsFunction<P, i32>) -> DrawBuilder<P> {
+        self.config.offset = Dynamic::Props(offset);
+        self
+    }
+
+    /// Draw the vertices `instances` times with a single draw call. Attributes given a
+    /// `.divisor()` advance once per instance, and the shader can read `gl_InstanceID`.
+    pub fn instances(mut self, instances: i32) -> DrawBuilder<P> {
+        self.config.instances = Some(Dynamic::Static(instances));
+        self
+    }
+
+    /// Compute the number of instances from the environment or props when the command runs.
+    pub fn instances_dynamic(mut self, instances: Dynamic<i32, P>) -> DrawBuilder<P> {
+        self.config.instances = Some(instances);
+        self
+    }
+
+    /// Advance an attribute once every `divisor` instances instead of once per vertex, e.g. 1
+    /// for an attribute with one value per instance.
+    pub fn divisor(mut self, name: &str, divisor: u32) -> DrawBuilder<P> {
+        self.config.divisors.insert(name.to_string(), divisor);
+        self
+    }
+
+    /// Configure the depth test, commands that don't get the default `Depth`.
+    pub fn depth(mut self, depth: Depth) -> DrawBuilder<P> {
+        self.config.depth = Some(depth);
+        self
+    }
+
+    /// Configure blending, commands that don't have it turned off.
+    pub fn blend(mut self, blend: Blend) -> DrawBuilder<P> {
+        self.config.blend = Some(blend);
+        self
+    }
+
+    /// Configure face culling, commands that don't have it turned off.
+    pub fn cull(mut self, cull: Cull) -> DrawBuilder<P> {
+        self.config.cull = Some(cull);
+        self
+    }
+
+    /// Which winding order faces the front, this defaults to counter clockwise.
+    pub fn front_face(mut self, front_face: FrontFace) -> DrawBuilder<P> {
+        self.config.front_face = Some(front_face);
         self
     }
 
-    pub fn count(mut self, count: i32) -> DrawBuilder {
-        self.config.count = count;
+    pub fn polygon_offset(mut self, polygon_offset: PolygonOffset) -> DrawBuilder<P> {
+        self.config.polygon_offset = Some(polygon_offset);
         self
     }
 
-    pub fn finalize(self) -> Box<Fn(&rugl::Environment)> {
+    /// Configure the stencil test, commands that don't have it turned off.
+    pub fn stencil(mut self, stencil: Stencil) -> DrawBuilder<P> {
+        self.config.stencil = Some(stencil);
+        self
+    }
+
+    /// Where to draw in the render target, this can be a `Rect` or a `Dynamic` function of
+    /// the environment or props. It defaults to the whole viewport.
+    pub fn viewport<D: Into<Dynamic<Rect, P>>>(mut self, viewport: D) -> DrawBuilder<P> {
+        self.config.viewport = Some(viewport.into());
+        self
+    }
+
+    /// Only draw inside of a rectangle, which can be static or dynamic like the viewport.
+    /// Commands without a scissor rectangle have the scissor test turned off.
+    pub fn scissor<D: Into<Dynamic<Rect, P>>>(mut self, scissor: D) -> DrawBuilder<P> {
+        self.config.scissor = Some(scissor.into());
+        self
+    }
+
+    /// Which of the red, green, blue and alpha channels are written, e.g. all `false` for a
+    /// depth only pass.
+    pub fn color_mask(mut self, color_mask: [bool; 4]) -> DrawBuilder<P> {
+        self.config.color_mask = Some(color_mask);
+        self
+    }
+
+    /// The width of `Primitive::Lines` in pixels. This panics if the width is outside of the
+    /// range that the GL supports, which is often only 1 in core profiles.
+    pub fn line_width(mut self, line_width: f32) -> DrawBuilder<P> {
+        let (min, max) = self.limits.line_width_range;
+        if line_width < min || line_width > max {
+            panic!(
+                "A line width of {} is not supported, it must be between {} and {}.",
+                line_width, min, max
+            );
+        }
+        self.config.line_width = Some(line_width);
+        self
+    }
+
+    /// The size of `Primitive::Points` in pixels, unless the vertex shader sets it. This panics
+    /// if the size is outside of `Limits::point_size_range`.
+    pub fn point_size(mut self, point_size: f32) -> DrawBuilder<P> {
+        let (min, max) = self.limits.point_size_range;
+        if point_size < min || point_size > max {
+            panic!(
+                "A point size of {} is not supported, it must be between {} and {}.",
+                point_size, min, max
+            );
+        }
+        self.config.point_size = Some(point_size);
+        self
+    }
+
+    /// Let the vertex shader set `gl_PointSize` for `Primitive::Points`, instead of using
+    /// `point_size()`. The size that the shader sets is clamped by the GL to
+    /// `Limits::point_size_range`.
+    pub fn program_point_size(mut self, enable: bool) -> DrawBuilder<P> {
+        self.config.program_point_size = Some(enable);
+        self
+    }
+
+    pub fn finalize_command(self) -> DrawCommand<P> {
         let mut config = self.config;
-        let vertex_shader = match config.vert {
-            Some(vert) => Some(gl_helpers::compile_shader(vert, gl::VERTEX_SHADER)),
-            None => None
-        };
-        let fragment_shader = match config.frag {
-            Some(frag) => Some(gl_helpers::compile_shader(frag, gl::FRAGMENT_SHADER)),
-            None => None
-        };
+        let backend = self.backend;
+        let current_context = self.current_context;
+        let vertex_arrays = self.vertex_arrays;
+        let scopes = self.scopes;
+        let vertex_shader = config.vert.map(|vert| gl_helpers::compile_shader(&*backend, vert, gl::VERTEX_SHADER));
+        let fragment_shader = config.frag.map(|frag| gl_helpers::compile_shader(&*backend, frag, gl::FRAGMENT_SHADER));
 
         let program = match (vertex_shader, fragment_shader) {
-            (Some(vs), Some(fs)) => Some(gl_helpers::link_program(&vs, &fs)),
+            (Some(vs), Some(fs)) => Some(gl_helpers::link_program(&*backend, &vs, &fs)),
             _ => None
         };
 
         let programs_attributes = match program {
-            Some(program) => gl_helpers::get_program_attributes(program),
+            Some(program) => gl_helpers::get_program_attributes(&*backend, program),
             _ => Vec::new()
         };
 
-        let buffers: Vec<Option<GLuint>> = programs_attributes.iter().map(|attribute_info| {
+        let attributes: Vec<Option<Attribute>> = programs_attributes.iter().map(|attribute_info| {
+            // The attribute that was given last wins.
             let attribute_config = config.attributes
                 .iter()
+                .rev()
                 .find(|attribute_tuple| {
                     attribute_tuple.0 == attribute_info.name
                 });
 
             match attribute_config {
-                Some(&(_, ref buffer)) => { Some(*buffer) },
+                Some((_, attribute)) => { Some(*attribute) },
                 _ => None
             }
         }).collect();
 
         let count = config.count;
+        let offset = config.offset;
+        let instances = config.instances;
 
-        let do_draw_elements = match config.elements {
-            Some(_) => true,
-            None => false
-        };
+        let do_draw_elements = config.elements.is_some();
 
         let draw_mode = config.primitive.to_gl_enum();
+        // The render state that the command leaves out comes from the scopes it runs in, so
+        // it's only resolved when the command runs.
+        let depth = config.depth;
+        let blend = config.blend;
+        let cull = config.cull;
+        let front_face = config.front_face;
+        let polygon_offset = config.polygon_offset;
+        let stencil = config.stencil;
+        let viewport = config.viewport.take();
+        let scissor = config.scissor.take();
+        let color_mask = config.color_mask;
+        let line_width = config.line_width.unwrap_or(1.0);
+        let point_size = config.point_size.unwrap_or(1.0);
+        let program_point_size = config.program_point_size.unwrap_or(false);
 
-        let vao = match program {
-            Some(_) => {
-                // Create a vertex array object that stores all of the attributes and buffer
-                // information.
-                let vao = gl_helpers::create_vao();
-                gl_helpers::bind_vao(vao);
-
-                // Go through each attribute, and bind it to the proper slot with the
-                // proper shapes.
-                for i in 0..programs_attributes.len() {
-                    let attribute_info = programs_attributes.get(i).unwrap();
-                    let buffer = buffers[i];
-                    match buffer {
-                        Some(vbo) => gl_helpers::bind_attribute_buffer(vbo, attribute_info),
-                        None => {}
+        // Only the attributes that the program uses, and that were given a buffer get bound.
+        // Attributes that read from a buffer chosen by the props are bound when the command
+        // runs instead, as are constants, which aren't stored in the vertex array object.
+        let mut attribute_sources = Vec::new();
+        let mut attribute_constants: Vec<(GLuint, GLenum, [f32; 4])> = Vec::new();
+        let mut bound_attributes: Vec<(AttributeInfo, Attribute)> = Vec::new();
+        let mut divisors: Vec<(GLuint, GLuint)> = Vec::new();
+        for (attribute_info, attribute) in programs_attributes.into_iter().zip(attributes) {
+            if let Some(divisor) = config.divisors.get(&attribute_info.name) {
+                divisors.push((attribute_info.index, *divisor));
+            }
+            let source_index = config.attribute_sources.iter().position(|source| {
+                source.0 == attribute_info.name
+            });
+            match (attribute, source_index) {
+                (_, Some(index)) => {
+                    let (_, source) = config.attribute_sources.swap_remove(index);
+                    attribute_sources.push((attribute_info, source));
+                },
+                (Some(attribute), None) => bound_attributes.push((attribute_info, attribute)),
+                (None, None) => {
+                    if let Some(value) = config.attribute_constants.get(&attribute_info.name) {
+                        if attribute_info.data_type == gl::DOUBLE {
+                            panic!(
+                                "The attribute {:?} is a double in the shader, which can't be \
+                                 given a constant.",
+                                attribute_info.name
+                            );
+                        }
+                        attribute_constants.push(
+                            (attribute_info.index, attribute_info.data_type, *value)
+                        );
                     }
                 }
+            };
+        }
+        let elements = config.elements;
 
-                match config.elements {
-                    Some(elements) => {
-                        log_draw!("gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, {:?})", elements);
-                        unsafe { gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, elements) }
-                    },
-                    None => {}
-                }
-
-                // Un-bind the vao, now when we bind it again, it will restore the state
-                // of our shader.
-                gl_helpers::bind_vao(0);
-                Some(vao)
-            },
-            None => None
-        };
+        // Vertex array objects can't be shared between contexts, so every window this command
+        // draws into gets its own, which is created the first time it's needed.
+        let mut vaos = HashMap::new();
+        if program.is_some() {
+            vaos.insert(
+                current_context.get(),
+                build_vao(
+                    &*backend,
+                    &vertex_arrays,
+                    &current_context,
+                    &bound_attributes,
+                    &divisors,
+                    elements
+                )
+            );
+        }
+        let vaos = RefCell::new(vaos);
 
         // Match up the uniform setters with their appropriate uniform infos from the program.
-        let matched_uniform_setters = match program {
-            Some(program) => {
-                let mut results = Vec::new();
-                let uniform_infos = gl_helpers::get_uniforms(program);
-                for uniform_info in uniform_infos.iter() {
-                    match config.uniform_setters.remove(&uniform_info.name) {
-                        Some(setter) => {
-                            let location = uniform_info.location as i32;
-                            let data_type = uniform_info.data_type;
-                            let data_size = uniform_info.data_size;
-                            results.push(
-                                Box::new(move |environment: &rugl::Environment| {
-                                    (
-                                        *setter(&environment)
-                                    )
-                                    .set_uniform(
-                                        location,
-                                        data_type,
-                                        data_size
-                                    );
-                                    check_gl_errors!();
-                                })
+        // Uniforms that only depend on the environment are set once per call or batch, while
+        // uniforms from the props are set for every item.
+        let mut matched_uniform_setters: Vec<SetUniform> = Vec::new();
+        let mut matched_uniform_prop_setters: Vec<PropsFunction<P, ()>> = Vec::new();
+        let mut inherited_uniforms = Vec::new();
+        if let Some(program) = program {
+            let uniform_infos = gl_helpers::get_uniforms(&*backend, program);
+            for uniform_info in uniform_infos.iter() {
+                let location = uniform_info.location;
+                let data_type = uniform_info.data_type;
+                let data_size = uniform_info.data_size;
+                if let Some(setter) = config.uniform_setters.remove(&uniform_info.name) {
+                    let backend = backend.clone();
+                    matched_uniform_setters.push(
+                        Box::new(move |environment: &rugl::Environment| {
+                            (
+                                *setter(environment)
+                            )
+                            .set_uniform(
+                                &*backend,
+                                location,
+                                data_type,
+                                data_size
                             );
-                        }
-                        None => {}
-                    };
+                            check_gl_errors!(backend);
+                        })
+                    );
+                } else if let Some(setter) = config.uniform_prop_setters.remove(&uniform_info.name) {
+                    let backend = backend.clone();
+                    matched_uniform_prop_setters.push(
+                        Box::new(move |environment: &rugl::Environment, props: &P| {
+                            (
+                                *setter(environment, props)
+                            )
+                            .set_uniform(
+                                &*backend,
+                                location,
+                                data_type,
+                                data_size
+                            );
+                            check_gl_errors!(backend);
+                        })
+                    );
+                } else {
+                    // Uniforms that the command doesn't set can come from a scope.
+                    inherited_uniforms.push(
+                        (uniform_info.name.clone(), location, data_type, data_size)
+                    );
                 }
-                results
-            },
-            None => Vec::new()
-        };
+            }
+        }
 
-        return Box::new(move |environment: &rugl::Environment| {
-            #[cfg(feature = "debug_draw")]
+        // Everything that doesn't depend on the props is bound once.
+        let draw_backend = backend.clone();
+        let draw_scopes = scopes.clone();
+        let bind = Box::new(move |environment: &rugl::Environment| {
+            #[cfg(feature = "log_draw")]
             println!("----------------------------------------------------");
-            match program {
-                Some(program) => {
-                    gl_helpers::use_program(program);
-                    gl_helpers::bind_vao(vao.unwrap());
-                    for setter in matched_uniform_setters.iter() {
-                        setter(environment);
+            if let Some(program) = program {
+                gl_helpers::use_program(&*backend, program);
+                let vao = *vaos.borrow_mut()
+                    .entry(current_context.get())
+                    .or_insert_with(|| {
+                        build_vao(
+                            &*backend,
+                            &vertex_arrays,
+                            &current_context,
+                            &bound_attributes,
+                            &divisors,
+                            elements
+                        )
+                    });
+                gl_helpers::bind_vao(&*backend, vao);
+                for &(index, data_type, value) in attribute_constants.iter() {
+                    gl_helpers::set_attribute_constant(&*backend, index, data_type, value);
+                }
+                depth.or_else(|| scopes.find(|scope| scope.depth))
+                    .unwrap_or_default()
+                    .apply(&*backend);
+                blend.or_else(|| scopes.find(|scope| scope.blend))
+                    .unwrap_or_default()
+                    .apply(&*backend);
+                cull.or_else(|| scopes.find(|scope| scope.cull))
+                    .unwrap_or_default()
+                    .apply(&*backend);
+                front_face.or_else(|| scopes.find(|scope| scope.front_face))
+                    .unwrap_or_default()
+                    .apply(&*backend);
+                polygon_offset.or_else(|| scopes.find(|scope| scope.polygon_offset))
+                    .unwrap_or_default()
+                    .apply(&*backend);
+                stencil.or_else(|| scopes.find(|scope| scope.stencil))
+                    .unwrap_or_default()
+                    .apply(&*backend);
+
+                let color_mask = color_mask
+                    .or_else(|| scopes.find(|scope| scope.color_mask))
+                    .unwrap_or([true, true, true, true]);
+                log_draw!("gl::ColorMask({:?})", color_mask);
+                backend.color_mask(color_mask[0], color_mask[1], color_mask[2], color_mask[3]);
+                log_draw!("gl::LineWidth({})", line_width);
+                backend.line_width(line_width);
+                log_draw!("gl::PointSize({})", point_size);
+                backend.point_size(point_size);
+                match program_point_size {
+                    true => {
+                        log_draw!("gl::Enable(gl::PROGRAM_POINT_SIZE)");
+                        backend.enable(gl::PROGRAM_POINT_SIZE);
+                    },
+                    false => {
+                        log_draw!("gl::Disable(gl::PROGRAM_POINT_SIZE)");
+                        backend.disable(gl::PROGRAM_POINT_SIZE);
                     }
+                };
+                for setter in matched_uniform_setters.iter() {
+                    setter(environment);
+                }
+                for &(ref name, location, data_type, data_size) in inherited_uniforms.iter() {
+                    if let Some(setter) = scopes.uniform(name) {
+                        (*setter(environment))
+                            .set_uniform(&*backend, location, data_type, data_size);
+                    }
+                }
+            };
+        });
 
-                    match do_draw_elements {
-                        true => gl_helpers::draw_elements(draw_mode, count),
-                        false => gl_helpers::draw_arrays(draw_mode, 0, count)
-                    };
-                },
-                None => {}
+        // Then every item sets what it needs from its props, and is drawn.
+        let draw = Box::new(move |environment: &rugl::Environment, props: &P| {
+            if program.is_none() {
+                return;
+            }
+            for (attribute_info, source) in attribute_sources.iter() {
+                gl_helpers::bind_attribute_buffer(
+                    &*draw_backend,
+                    &Attribute::new(source(environment, props)),
+                    attribute_info
+                );
+            }
+
+            let viewport_rect = match viewport {
+                Some(ref viewport) => viewport.get(environment, props),
+                None => match draw_scopes.find(|scope| scope.viewport.clone()) {
+                    Some(viewport) => viewport.get(environment, &()),
+                    None => Rect::full(environment)
+                }
+            };
+            viewport_rect.apply_viewport(&*draw_backend);
+            let scissor_rect = match scissor {
+                Some(ref scissor) => Some(scissor.get(environment, props)),
+                None => {
+                    draw_scopes.find(|scope| scope.scissor.clone())
+                        .map(|scissor| scissor.get(environment, &()))
+                }
+            };
+            match scissor_rect {
+                Some(scissor_rect) => scissor_rect.apply_scissor(&*draw_backend),
+                None => {
+                    log_draw!("gl::Disable(gl::SCISSOR_TEST)");
+                    draw_backend.disable(gl::SCISSOR_TEST);
+                }
+            };
+
+            for setter in matched_uniform_prop_setters.iter() {
+                setter(environment, props);
+            }
+
+            let count = count.get(environment, props);
+            let offset = offset.get(environment, props);
+            let instances = instances.as_ref().map(|instances| instances.get(environment, props));
+            match (do_draw_elements, instances) {
+                (true, Some(instances)) => gl_helpers::draw_elements_instanced(
+                    &*draw_backend, draw_mode, offset, count, instances
+                ),
+                (false, Some(instances)) => gl_helpers::draw_arrays_instanced(
+                    &*draw_backend, draw_mode, offset, count, instances
+                ),
+                (true, None) => gl_helpers::draw_elements(&*draw_backend, draw_mode, offset, count),
+                (false, None) => gl_helpers::draw_arrays(&*draw_backend, draw_mode, offset, count)
             };
-        })
+        });
+
+        DrawCommand {
+            bind,
+            draw
+        }
+    }
+}
+
+impl DrawBuilder<()> {
+    pub fn finalize(self) -> Box<dyn Fn(&rugl::Environment)> {
+        let command = self.finalize_command();
+        Box::new(move |environment: &rugl::Environment| command.call(environment, &()))
+    }
+}
+
+/// Create a vertex array object that stores all of the attributes and buffer information, and
+/// remember that it belongs to the current context.
+fn build_vao(
+    backend: &dyn GlBackend,
+    vertex_arrays: &VertexArrays,
+    current_context: &Cell<usize>,
+    bound_attributes: &[(AttributeInfo, Attribute)],
+    divisors: &[(GLuint, GLuint)],
+    elements: Option<GLuint>
+) -> GLuint {
+    let vao = gl_helpers::create_vao(backend);
+    vertex_arrays.borrow_mut()
+        .entry(current_context.get())
+        .or_default()
+        .push(vao);
+    gl_helpers::bind_vao(backend, vao);
+
+    // Go through each attribute, and bind it to the proper slot with the proper shapes.
+    for (attribute_info, attribute) in bound_attributes.iter() {
+        gl_helpers::bind_attribute_buffer(backend, attribute, attribute_info);
+    }
+
+    for &(index, divisor) in divisors.iter() {
+        log_draw!("gl::VertexAttribDivisor({}, {})", index, divisor);
+        backend.vertex_attrib_divisor(index, divisor);
+    }
+
+    if let Some(elements) = elements {
+        log_draw!("gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, {:?})", elements);
+        backend.bind_buffer(gl::ELEMENT_ARRAY_BUFFER, elements);
+    }
+
+    // Un-bind the vao, now when we bind it again, it will restore the state of our shader.
+    gl_helpers::bind_vao(backend, 0);
+    vao
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gl;
+    use super::gl::types::*;
+    use super::{DrawBuilder, VertexArrays};
+    use super::super::Primitive;
+    use super::super::attributes::{Attribute, ComponentType};
+    use super::rugl::{init_with_backend, Environment};
+    use super::super::state::{Comparison, Cull, Depth, Dynamic, Face, FrontFace, Limits};
+    use super::super::state::{PolygonOffset, Rect};
+    use super::super::recording_backend::RecordingBackend;
+    use super::super::scope::ScopeStack;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    static VERT: &str = "
+        #version 150
+        in vec2 position;
+        in float id;
+        uniform float time;
+        void main() {
+            gl_Position = vec4(position, id * time, 1.0);
+        }
+    ";
+
+    static FRAG: &str = "
+        #version 150
+        out vec4 out_color;
+        uniform vec3 color;
+        void main() {
+            out_color = vec4(color, 1.0);
+        }
+    ";
+
+    /// Run the draw in a 100x100 environment, and return the calls that it made.
+    fn calls_during<F: Fn(&Environment)>(backend: &RecordingBackend, draw: F) -> Vec<String> {
+        backend.clear_calls();
+        draw(&Environment::new(100, 100));
+        backend.calls()
+    }
+
+    /// Keep the calls that start with one of the `prefixes`, in the order that they were made.
+    fn calls_starting_with(calls: &[String], prefixes: &[&str]) -> Vec<String> {
+        calls.iter()
+            .filter(|call| prefixes.iter().any(|prefix| call.starts_with(prefix)))
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn only_supplied_attributes_are_bound() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        let _draw = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+            .count(3)
+            .finalize();
+
+        let calls = backend.calls();
+        assert_eq!(calls_starting_with(&calls, &["EnableVertexAttribArray", "VertexAttribPointer"]), vec![
+            "EnableVertexAttribArray(0)".to_string(),
+            format!("VertexAttribPointer(0, 2, {}, false, 0, 0)", gl::FLOAT)
+        ]);
+    }
+
+    #[test]
+    fn uniforms_are_set_at_their_locations() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        let draw = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+            .uniform("time", Box::new(|_| Box::new(0.5f32)))
+            .uniform("color", Box::new(|_| Box::new([1.0f32, 0.0, 0.0])))
+            .count(3)
+            .finalize();
+
+        let calls = calls_during(&backend, &draw);
+        assert_eq!(calls_starting_with(&calls, &["Uniform", "DrawArrays"]), vec![
+            "Uniform1fv(0, [0.5])".to_string(),
+            "Uniform3fv(1, [1.0, 0.0, 0.0])".to_string(),
+            format!("DrawArrays({}, 0, 3)", gl::TRIANGLES)
+        ]);
+    }
+
+    #[test]
+    fn commands_are_computed_from_props() {
+        struct Props {
+            time: f32,
+            first: i32,
+            buffer: GLuint
+        }
+
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+        let ids = rugl.buffer(&vec![0.0f32, 1.0, 2.0]);
+
+        let draw = rugl.draw_with_props::<Props>()
+            .vert(VERT)
+            .frag(FRAG)
+            .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+            .attribute_prop("id", Box::new(|_, props: &Props| props.buffer))
+            .uniform_prop("time", Box::new(|_, props: &Props| Box::new(props.time)))
+            .offset_prop(Box::new(|_, props: &Props| props.first))
+            .count(3)
+            .finalize_command();
+
+        let props = Props { time: 0.5, first: 3, buffer: ids };
+        let calls = calls_during(&backend, |env| draw.call(env, &props));
+        assert_eq!(calls_starting_with(&calls, &["BindBuffer", "Uniform1fv", "DrawArrays"]), vec![
+            format!("BindBuffer({}, {})", gl::ARRAY_BUFFER, ids),
+            "Uniform1fv(0, [0.5])".to_string(),
+            format!("DrawArrays({}, 3, 3)", gl::TRIANGLES)
+        ]);
+    }
+
+    #[test]
+    fn batches_only_bind_once() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        let draw = rugl.draw_with_props::<f32>()
+            .vert(VERT)
+            .frag(FRAG)
+            .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+            .uniform_prop("time", Box::new(|_, time: &f32| Box::new(*time)))
+            .uniform("color", Box::new(|_| Box::new([1.0f32, 0.0, 0.0])))
+            .count(3)
+            .finalize_command();
+
+        let calls = calls_during(&backend, |env| draw.batch(env, &[0.25, 0.5, 0.5]));
+        let draw_arrays = format!("DrawArrays({}, 0, 3)", gl::TRIANGLES);
+        assert_eq!(calls_starting_with(&calls, &["UseProgram"]).len(), 1);
+        assert_eq!(calls_starting_with(&calls, &["Uniform", "DrawArrays"]), vec![
+            "Uniform3fv(1, [1.0, 0.0, 0.0])".to_string(),
+            "Uniform1fv(0, [0.25])".to_string(),
+            draw_arrays.clone(),
+            "Uniform1fv(0, [0.5])".to_string(),
+            draw_arrays.clone(),
+            draw_arrays
+        ]);
+    }
+
+    #[test]
+    fn settings_are_inherited_from_scopes() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        let scope = rugl.scope()
+            .uniform("time", Box::new(|_| Box::new(0.25f32)))
+            .uniform("color", Box::new(|_| Box::new([0.0f32, 1.0, 0.0])))
+            .viewport(Rect::new(0, 0, 50, 50))
+            .depth(Depth::disabled())
+            .finalize();
+
+        let draw = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .uniform("color", Box::new(|_| Box::new([1.0f32, 0.0, 0.0])))
+            .count(3)
+            .finalize();
+
+        let calls = calls_during(&backend, |env| scope.run(env, |env| draw(env)));
+        let enable_depth = format!("Enable({})", gl::DEPTH_TEST);
+        let disable_depth = format!("Disable({})", gl::DEPTH_TEST);
+        let state = ["Uniform", "Viewport", &enable_depth, &disable_depth];
+        assert_eq!(calls_starting_with(&calls, &state), vec![
+            disable_depth.clone(),
+            "Uniform3fv(1, [1.0, 0.0, 0.0])".to_string(),
+            "Uniform1fv(0, [0.25])".to_string(),
+            "Viewport(0, 0, 50, 50)".to_string()
+        ]);
+
+        let calls = calls_during(&backend, &draw);
+        assert_eq!(calls_starting_with(&calls, &state), vec![
+            enable_depth,
+            "Viewport(0, 0, 100, 100)".to_string()
+        ]);
+    }
+
+    #[test]
+    fn instanced_attributes_get_divisors() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        let draw = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+            .attribute("id", &(0..100).map(|id| id as f32).collect::<Vec<f32>>())
+            .divisor("id", 1)
+            .instances(100)
+            .count(3)
+            .finalize();
+
+        let calls = backend.calls();
+        assert_eq!(calls_starting_with(&calls, &["VertexAttribDivisor"]), vec![
+            "VertexAttribDivisor(1, 1)".to_string()
+        ]);
+
+        let calls = calls_during(&backend, &draw);
+        assert_eq!(calls_starting_with(&calls, &["DrawArrays"]), vec![
+            format!("DrawArraysInstanced({}, 0, 3, 100)", gl::TRIANGLES)
+        ]);
+    }
+
+    #[test]
+    fn attributes_can_describe_packed_buffers() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+        let colors = rugl.buffer(&vec![[255u8, 0, 0, 255], [0, 255, 0, 255], [0, 0, 255, 255]]);
+
+        let _draw = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+            .attribute("id", Attribute::new(colors)
+                .component_type(ComponentType::U8)
+                .normalized(true)
+                .stride(4)
+                .offset(3))
+            .count(3)
+            .finalize();
+
+        let calls = backend.calls();
+        assert_eq!(calls_starting_with(&calls, &["VertexAttribPointer"]), vec![
+            format!("VertexAttribPointer(0, 2, {}, false, 0, 0)", gl::FLOAT),
+            format!("VertexAttribPointer(1, 1, {}, true, 4, 3)", gl::UNSIGNED_BYTE)
+        ]);
+    }
+
+    #[test]
+    fn vertex_buffers_bind_every_field_with_its_offset() {
+        #[repr(C)]
+        struct Vertex {
+            position: [f32; 2],
+            id: u16
+        }
+
+        vertex_layout!(Vertex { position, id });
+
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+        let vertices = rugl.vertex_buffer(&[
+            Vertex { position: [0.0, 0.5], id: 1 },
+            Vertex { position: [0.5, -0.5], id: 2 },
+            Vertex { position: [-0.5, -0.5], id: 3 }
+        ]);
+
+        let _draw = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .vertex_buffer(&vertices)
+            .count(3)
+            .finalize();
+
+        let calls = backend.calls();
+        assert_eq!(calls_starting_with(&calls, &["VertexAttribPointer"]), vec![
+            format!("VertexAttribPointer(0, 2, {}, false, 12, 0)", gl::FLOAT),
+            format!("VertexAttribPointer(1, 1, {}, false, 12, 8)", gl::UNSIGNED_SHORT)
+        ]);
+    }
+
+    #[test]
+    fn integer_and_double_attributes_are_not_converted_to_floats() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        let _draw = rugl.draw()
+            .vert("
+                #version 410
+                in vec2 position;
+                in uvec4 joints;
+                in int id;
+                in dvec2 offset;
+                void main() {
+                    gl_Position = vec4(position + vec2(offset), float(id + int(joints.x)), 1.0);
+                }
+            ")
+            .frag(FRAG)
+            .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+            .attribute("joints", &vec![[0u8, 1, 2, 3], [1, 2, 3, 4], [2, 3, 4, 5]])
+            .attribute("id", &vec![7i32, 8, 9])
+            .attribute("offset", &vec![[0.0f64, 0.0], [0.0, 1.0], [1.0, 0.0]])
+            .count(3)
+            .finalize();
+
+        let calls = backend.calls();
+        assert_eq!(calls_starting_with(&calls, &["VertexAttrib"]), vec![
+            format!("VertexAttribPointer(0, 2, {}, false, 0, 0)", gl::FLOAT),
+            format!("VertexAttribIPointer(1, 4, {}, 0, 0)", gl::UNSIGNED_BYTE),
+            format!("VertexAttribIPointer(2, 1, {}, 0, 0)", gl::INT),
+            format!("VertexAttribLPointer(3, 2, {}, 0, 0)", gl::DOUBLE)
+        ]);
+    }
+
+    #[test]
+    fn missing_attributes_can_be_given_constants() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        let draw = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+            .attribute_constant("position", [1.0, 1.0, 0.0, 1.0])
+            .attribute_constant("id", [3.0, 0.0, 0.0, 1.0])
+            .count(3)
+            .finalize();
+        let attributes = ["EnableVertexAttribArray", "VertexAttrib"];
+        assert_eq!(calls_starting_with(&backend.calls(), &attributes), vec![
+            "EnableVertexAttribArray(0)".to_string(),
+            format!("VertexAttribPointer(0, 2, {}, false, 0, 0)", gl::FLOAT)
+        ]);
+
+        let calls = calls_during(&backend, &draw);
+        assert_eq!(calls_starting_with(&calls, &attributes), vec![
+            "VertexAttrib4f(1, 3.0, 0.0, 0.0, 1.0)".to_string()
+        ]);
+    }
+
+    #[test]
+    fn integer_constants_are_not_converted_to_floats() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        let draw = rugl.draw()
+            .vert("
+                #version 150
+                in vec2 position;
+                in int id;
+                in uvec2 joints;
+                void main() {
+                    gl_Position = vec4(position, float(id + int(joints.x)), 1.0);
+                }
+            ")
+            .frag(FRAG)
+            .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+            .attribute_constant("id", [-3.0, 0.0, 0.0, 1.0])
+            .attribute_constant("joints", [4.0, 5.0, 0.0, 1.0])
+            .count(3)
+            .finalize();
+
+        let calls = calls_during(&backend, &draw);
+        assert_eq!(calls_starting_with(&calls, &["VertexAttrib"]), vec![
+            "VertexAttribI4i(1, -3, 0, 0, 1)".to_string(),
+            "VertexAttribI4ui(2, 4, 5, 0, 1)".to_string()
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is a double in the shader, which can't be given a constant")]
+    fn double_attributes_cant_have_constants() {
+        let rugl = init_with_backend(Rc::new(RecordingBackend::new()));
+
+        let _draw = rugl.draw()
+            .vert("
+                #version 410
+                in vec2 position;
+                in double depth;
+                void main() {
+                    gl_Position = vec4(position, float(depth), 1.0);
+                }
+            ")
+            .frag(FRAG)
+            .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+            .attribute_constant("depth", [0.5, 0.0, 0.0, 1.0])
+            .count(3)
+            .finalize();
+    }
+
+    #[test]
+    fn every_context_gets_its_own_vao() {
+        let backend = Rc::new(RecordingBackend::new());
+        let current_context = Rc::new(Cell::new(0));
+
+        let vertex_arrays = VertexArrays::default();
+        let limits = Limits::query(&*backend);
+        let scopes = ScopeStack::new();
+
+        let draw = DrawBuilder::new(
+            backend.clone(),
+            current_context.clone(),
+            vertex_arrays.clone(),
+            limits,
+            scopes
+        )
+            .vert(VERT)
+            .frag(FRAG)
+            .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+            .count(3)
+            .finalize();
+
+        let first_vao = vertex_arrays.borrow()[&0][0];
+        let calls = calls_during(&backend, &draw);
+        let vertex_array_calls = ["GenVertexArrays", "BindVertexArray"];
+        assert_eq!(calls_starting_with(&calls, &vertex_array_calls), vec![
+            format!("BindVertexArray({})", first_vao)
+        ]);
+
+        current_context.set(1);
+        let calls = calls_during(&backend, |env| {
+            draw(env);
+            draw(env);
+        });
+        let second_vao = vertex_arrays.borrow()[&1][0];
+        assert_eq!(calls_starting_with(&calls, &vertex_array_calls), vec![
+            format!("GenVertexArrays() -> {}", second_vao),
+            format!("BindVertexArray({})", second_vao),
+            "BindVertexArray(0)".to_string(),
+            format!("BindVertexArray({})", second_vao),
+            format!("BindVertexArray({})", second_vao)
+        ]);
+        assert_ne!(first_vao, second_vao);
+    }
+
+    #[test]
+    fn depth_state_is_applied_per_command() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        let draw_default = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .count(3)
+            .finalize();
+
+        let draw_overlay = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .count(3)
+            .depth(Depth::disabled())
+            .finalize();
+
+        let draw_transparent = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .count(3)
+            .depth(Depth { func: Comparison::LessEqual, mask: false, ..Depth::default() })
+            .finalize();
+
+        let enable_depth = format!("Enable({})", gl::DEPTH_TEST);
+        let disable_depth = format!("Disable({})", gl::DEPTH_TEST);
+        let depth = [&enable_depth, &disable_depth, "DepthFunc", "DepthMask"];
+
+        let calls = calls_during(&backend, &draw_default);
+        assert_eq!(calls_starting_with(&calls, &depth), vec![
+            enable_depth.clone(),
+            format!("DepthFunc({})", gl::LESS),
+            "DepthMask(true)".to_string()
+        ]);
+
+        let calls = calls_during(&backend, &draw_overlay);
+        assert_eq!(calls_starting_with(&calls, &depth), vec![disable_depth.clone()]);
+
+        let calls = calls_during(&backend, &draw_transparent);
+        assert_eq!(calls_starting_with(&calls, &depth), vec![
+            enable_depth,
+            format!("DepthFunc({})", gl::LEQUAL),
+            "DepthMask(false)".to_string()
+        ]);
+    }
+
+    #[test]
+    fn culling_and_polygon_offset_are_applied_per_command() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        let draw_culled = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .count(3)
+            .cull(Cull { enable: true, face: Face::Front })
+            .front_face(FrontFace::Clockwise)
+            .polygon_offset(PolygonOffset::new(1.0, 2.0))
+            .finalize();
+
+        let draw_default = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .count(3)
+            .finalize();
+
+        let enable_cull = format!("Enable({})", gl::CULL_FACE);
+        let disable_cull = format!("Disable({})", gl::CULL_FACE);
+        let enable_offset = format!("Enable({})", gl::POLYGON_OFFSET_FILL);
+        let disable_offset = format!("Disable({})", gl::POLYGON_OFFSET_FILL);
+        let faces = [
+            &enable_cull, &disable_cull, "CullFace", "FrontFace",
+            &enable_offset, &disable_offset, "PolygonOffset"
+        ];
+
+        let calls = calls_during(&backend, &draw_culled);
+        assert_eq!(calls_starting_with(&calls, &faces), vec![
+            enable_cull.clone(),
+            format!("CullFace({})", gl::FRONT),
+            format!("FrontFace({})", gl::CW),
+            enable_offset.clone(),
+            "PolygonOffset(1.0, 2.0)".to_string()
+        ]);
+
+        let calls = calls_during(&backend, &draw_default);
+        assert_eq!(calls_starting_with(&calls, &faces), vec![
+            disable_cull,
+            format!("FrontFace({})", gl::CCW),
+            disable_offset
+        ]);
+    }
+
+    #[test]
+    fn viewport_can_be_computed_from_the_environment() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+
+        let draw = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .count(3)
+            .viewport(Dynamic::Function(Box::new(|env: &Environment| {
+                Rect::new(0, 0, env.viewport_width as i32 / 2, env.viewport_height as i32)
+            })))
+            .scissor(Rect::new(10, 10, 20, 20))
+            .finalize();
+
+        let calls = calls_during(&backend, &draw);
+        let scissor_test = format!("Enable({})", gl::SCISSOR_TEST);
+        assert_eq!(calls_starting_with(&calls, &["Viewport", &scissor_test, "Scissor"]), vec![
+            "Viewport(0, 0, 50, 100)".to_string(),
+            scissor_test.clone(),
+            "Scissor(10, 10, 20, 20)".to_string()
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "A line width of 4 is not supported")]
+    fn unsupported_line_widths_are_rejected() {
+        let backend = Rc::new(RecordingBackend::new());
+        backend.set_floats(gl::ALIASED_LINE_WIDTH_RANGE, vec![1.0, 1.0]);
+        let rugl = init_with_backend(backend.clone());
+
+        rugl.draw().line_width(4.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "A point size of 128 is not supported")]
+    fn unsupported_point_sizes_are_rejected() {
+        let backend = Rc::new(RecordingBackend::new());
+        backend.set_floats(gl::POINT_SIZE_RANGE, vec![1.0, 64.0]);
+        let rugl = init_with_backend(backend.clone());
+
+        rugl.draw().point_size(128.0);
+    }
+
+    #[test]
+    fn color_mask_and_point_sizes_are_applied_per_command() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+        let points = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .primitive(Primitive::Points)
+            .color_mask([true, false, true, false])
+            .point_size(8.0)
+            .program_point_size(true)
+            .count(3)
+            .finalize();
+        let plain = rugl.draw()
+            .vert(VERT)
+            .frag(FRAG)
+            .count(3)
+            .finalize();
+        let enable_point_size = format!("Enable({})", gl::PROGRAM_POINT_SIZE);
+        let disable_point_size = format!("Disable({})", gl::PROGRAM_POINT_SIZE);
+        let masks = ["ColorMask", "PointSize", &enable_point_size, &disable_point_size];
+
+        let calls = calls_during(&backend, &points);
+        assert_eq!(calls_starting_with(&calls, &masks), vec![
+            "ColorMask(true, false, true, false)".to_string(),
+            "PointSize(8.0)".to_string(),
+            enable_point_size.clone()
+        ]);
+
+        let calls = calls_during(&backend, &plain);
+        assert_eq!(calls_starting_with(&calls, &masks), vec![
+            "ColorMask(true, true, true, true)".to_string(),
+            "PointSize(1.0)".to_string(),
+            disable_point_size
+        ]);
     }
 }
@@ -0,0 +1,118 @@
+use super::backend::GlBackend;
+use super::gl;
+use super::gl::types::*;
+use super::gl_helpers;
+use super::pixels::{PixelFormat, Pixels};
+use std::rc::Rc;
+
+/// An offscreen render target with an RGBA8 color attachment and a combined 24 bit depth and
+/// 8 bit stencil attachment.
+pub struct Framebuffer {
+    pub width: u32,
+    pub height: u32,
+    framebuffer: GLuint,
+    color: GLuint,
+    depth_stencil: GLuint,
+    backend: Rc<dyn GlBackend>
+}
+
+impl Framebuffer {
+    /// Allocate the framebuffer and its attachments. This panics if the driver reports that
+    /// the framebuffer is incomplete.
+    pub fn new(backend: Rc<dyn GlBackend>, width: u32, height: u32) -> Framebuffer {
+        let framebuffer = gl_helpers::create_framebuffer(&*backend);
+        gl_helpers::bind_framebuffer(&*backend, framebuffer);
+
+        let color = gl_helpers::attach_renderbuffer(
+            &*backend,
+            gl::COLOR_ATTACHMENT0,
+            gl::RGBA8,
+            width as GLsizei,
+            height as GLsizei
+        );
+        let depth_stencil = gl_helpers::attach_renderbuffer(
+            &*backend,
+            gl::DEPTH_STENCIL_ATTACHMENT,
+            gl::DEPTH24_STENCIL8,
+            width as GLsizei,
+            height as GLsizei
+        );
+        gl_helpers::check_framebuffer_status(&*backend);
+
+        // Make sure the gl state is clean.
+        gl_helpers::bind_framebuffer(&*backend, 0);
+
+        Framebuffer {
+            width,
+            height,
+            framebuffer,
+            color,
+            depth_stencil,
+            backend
+        }
+    }
+
+    /// Bind the framebuffer as the current render target, and set the viewport to cover it.
+    pub fn bind(&self) {
+        gl_helpers::bind_framebuffer(&*self.backend, self.framebuffer);
+        gl_helpers::viewport(&*self.backend, 0, 0, self.width as GLsizei, self.height as GLsizei);
+    }
+
+    /// Read back a rectangle of the framebuffer, with `x` and `y` measured from the top left.
+    /// This doesn't change which framebuffer is being drawn into.
+    pub fn read_pixels(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        format: PixelFormat
+    ) -> Pixels {
+        let previous = self.backend.get_integer_v(gl::READ_FRAMEBUFFER_BINDING) as GLuint;
+        self.backend.bind_framebuffer(gl::READ_FRAMEBUFFER, self.framebuffer);
+        let pixels = Pixels::read(
+            &*self.backend,
+            x,
+            y,
+            width,
+            height,
+            self.width,
+            self.height,
+            format
+        );
+        self.backend.bind_framebuffer(gl::READ_FRAMEBUFFER, previous);
+        pixels
+    }
+}
+
+/// Release the framebuffer and its attachments, which needs the context that created them to
+/// still be alive.
+impl Drop for Framebuffer {
+    fn drop(&mut self) {
+        gl_helpers::delete_framebuffer(&*self.backend, self.framebuffer);
+        gl_helpers::delete_renderbuffer(&*self.backend, self.color);
+        gl_helpers::delete_renderbuffer(&*self.backend, self.depth_stencil);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::recording_backend::RecordingBackend;
+
+    #[test]
+    fn dropping_deletes_the_framebuffer_and_its_attachments() {
+        let backend = Rc::new(RecordingBackend::new());
+        let framebuffer = Framebuffer::new(backend.clone(), 10, 10);
+        let names = (framebuffer.framebuffer, framebuffer.color, framebuffer.depth_stencil);
+
+        backend.clear_calls();
+        drop(framebuffer);
+
+        assert_eq!(backend.calls(), vec![
+            format!("DeleteFramebuffers({})", names.0),
+            format!("DeleteRenderbuffers({})", names.1),
+            format!("DeleteRenderbuffers({})", names.2)
+        ]);
+    }
+}
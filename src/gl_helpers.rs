@@ -1,10 +1,10 @@
+use super::attributes::Attribute;
+use super::backend::GlBackend;
 use super::gl;
 use super::gl::types::*;
-use std::ffi::CString;
-use std::ptr;
-use std::str;
 use std::mem;
 use std::fmt;
+use std::slice;
 
 pub struct AttributeInfo {
     pub name: String,
@@ -61,330 +61,421 @@ impl fmt::Debug for UniformInfo {
     }
 }
 
-pub fn compile_shader(source: &str, shader_type: GLenum) -> GLuint {
-    unsafe {
-        log_draw!("gl::CreateShader(shader_type:{})", gl_shader_type_enum_to_string(shader_type));
-        let shader = gl::CreateShader(shader_type);
-        log_draw!("gl::CreateShader -> {:?}", shader);
-
-        // Attempt to compile the shader
-        let c_str = CString::new(source.as_bytes()).unwrap();
-        log_draw!("\"{}\n\"", source);
-        log_draw!("gl::ShaderSource(shader:{:?}, count:{:?}, source:{:?}, ptr::null())", shader, 1, &c_str.as_ptr());
-        gl::ShaderSource(shader, 1, &c_str.as_ptr(), ptr::null());
-
-        log_draw!("gl::CompileShader({:?})", shader);
-        gl::CompileShader(shader);
-
-        // Get the compile status
-        let mut status: GLint = mem::uninitialized();
-        log_draw!("gl::GetShaderiv(shader:{:?}, gl::COMPILE_STATUS, *status)", shader);
-        gl::GetShaderiv(shader, gl::COMPILE_STATUS, &mut status);
-        log_draw!("    status -> {}", status);
-
-        // Fail on error
-        if status != (gl::TRUE as GLint) {
-            let mut log_length: GLint = mem::uninitialized();
-            gl::GetShaderiv(shader, gl::INFO_LOG_LENGTH, &mut log_length);
-            let mut log = Vec::with_capacity(log_length as usize);
-            log.set_len((log_length as usize) - 1); // subtract 1 to skip the trailing null character
-            gl::GetShaderInfoLog(shader, log_length, ptr::null_mut(), log.as_mut_ptr() as *mut GLchar);
-            panic!("There was an error compiling the shader: {}", str::from_utf8(&log).ok().expect("ShaderInfoLog not valid utf8"));
-        }
-        shader
+/// Re-interpret a slice of tightly packed values, e.g. `&[[f32; 3]]`, as a flat slice of its
+/// components.
+///
+/// # Safety
+///
+/// `T` must be laid out as a whole number of `U`s.
+pub unsafe fn flatten<T, U>(data: &[T]) -> &[U] {
+    slice::from_raw_parts(
+        data.as_ptr() as *const U,
+        std::mem::size_of_val(data) / mem::size_of::<U>()
+    )
+}
+
+pub fn compile_shader(backend: &dyn GlBackend, source: &str, shader_type: GLenum) -> GLuint {
+    log_draw!("gl::CreateShader(shader_type:{})", gl_shader_type_enum_to_string(shader_type));
+    let shader = backend.create_shader(shader_type);
+    log_draw!("gl::CreateShader -> {:?}", shader);
+
+    // Attempt to compile the shader
+    log_draw!("\"{}\n\"", source);
+    log_draw!("gl::ShaderSource(shader:{:?}, count:{:?}, *source, ptr::null())", shader, 1);
+    backend.shader_source(shader, source);
+
+    log_draw!("gl::CompileShader({:?})", shader);
+    backend.compile_shader(shader);
+
+    // Get the compile status
+    log_draw!("gl::GetShaderiv(shader:{:?}, gl::COMPILE_STATUS, *status)", shader);
+    let status = backend.get_shader_iv(shader, gl::COMPILE_STATUS);
+    log_draw!("    status -> {}", status);
+
+    // Fail on error
+    if status != (gl::TRUE as GLint) {
+        panic!("There was an error compiling the shader: {}", backend.get_shader_info_log(shader));
     }
+    shader
 }
 
-pub fn link_program(vertex_shader: &GLuint, fragment_shader: &GLuint) -> GLuint {
-    unsafe {
-        let program = gl::CreateProgram();
-        log_draw!("gl::CreateProgram() -> {:?}", program);
-        log_draw!("gl::AttachShader(program:{:?}, vertex_shader:{:?})", program, *vertex_shader);
-        gl::AttachShader(program, *vertex_shader);
-        log_draw!("gl::AttachShader(program:{:?}, fragment_shader:{:?})", program, *fragment_shader);
-        gl::AttachShader(program, *fragment_shader);
-        log_draw!("gl::LinkProgram(program:{:?})", program);
-        gl::LinkProgram(program);
-
-        // Get the link status
-        let mut status: GLint = mem::uninitialized();
-        log_draw!("gl::GetProgramiv(program:{:?}, gl::LINK_STATUS, &mut status)", program);
-        gl::GetProgramiv(program, gl::LINK_STATUS, &mut status);
-        log_draw!("    status -> {}", status);
-
-        // Fail on error
-        if status != (gl::TRUE as GLint) {
-            let mut log_length: GLint = mem::uninitialized();
-            gl::GetProgramiv(program, gl::INFO_LOG_LENGTH, &mut log_length);
-            let mut log_buffer = Vec::with_capacity(log_length as usize);
-            log_buffer.set_len((log_length as usize) - 1); // subtract 1 to skip the trailing null character
-            gl::GetProgramInfoLog(program, log_length, ptr::null_mut(), log_buffer.as_mut_ptr() as *mut GLchar);
-            panic!("There was an error linking the shader: {}", str::from_utf8(&log_buffer).ok().expect("ProgramInfoLog not valid utf8"));
-        }
-        program
+pub fn link_program(backend: &dyn GlBackend, vertex_shader: &GLuint, fragment_shader: &GLuint) -> GLuint {
+    let program = backend.create_program();
+    log_draw!("gl::CreateProgram() -> {:?}", program);
+    log_draw!("gl::AttachShader(program:{:?}, vertex_shader:{:?})", program, *vertex_shader);
+    backend.attach_shader(program, *vertex_shader);
+    log_draw!("gl::AttachShader(program:{:?}, fragment_shader:{:?})", program, *fragment_shader);
+    backend.attach_shader(program, *fragment_shader);
+    log_draw!("gl::LinkProgram(program:{:?})", program);
+    backend.link_program(program);
+
+    // Get the link status
+    log_draw!("gl::GetProgramiv(program:{:?}, gl::LINK_STATUS, &mut status)", program);
+    let status = backend.get_program_iv(program, gl::LINK_STATUS);
+    log_draw!("    status -> {}", status);
+
+    // Fail on error
+    if status != (gl::TRUE as GLint) {
+        panic!("There was an error linking the shader: {}", backend.get_program_info_log(program));
     }
+    program
 }
 
-pub fn use_program(program: GLuint) {
+pub fn use_program(backend: &dyn GlBackend, program: GLuint) {
     log_draw!("gl::UseProgram(program:{:?})", program);
-    unsafe {
-        gl::UseProgram(program);
-    };
+    backend.use_program(program);
 }
 
-pub fn create_buffer(vertex_data: &[GLfloat]) -> GLuint {
-    unsafe {
-        // Create a vertex buffer object and copy the vertex data to it.
-        let mut buffer: GLuint = mem::uninitialized();
-        log_draw!("gl::GenBuffers(size:1, *buffer)");
-        gl::GenBuffers(1, &mut buffer);
-        log_draw!("    buffer -> {}", buffer);
-
-        log_draw!("gl::BindBuffer(gl::ARRAY_BUFFER, buffer:{:?})", buffer);
-        gl::BindBuffer(gl::ARRAY_BUFFER, buffer);
-
-        let size = (vertex_data.len() * mem::size_of::<GLfloat>()) as GLsizeiptr;
-        log_draw!(
-            "gl::BufferData(gl::ARRAY_BUFFER, size:{:?}, *data, gl::STATIC_DRAW)",
-            size
-        );
-        // log_draw!("    vertex_data: {:?}", vertex_data);
-        log_draw!("    GLFloat size: {}", mem::size_of::<GLfloat>());
-        gl::BufferData(
-            gl::ARRAY_BUFFER,
-            size,
-            mem::transmute(&vertex_data[0]),
-            gl::STATIC_DRAW
-        );
-
-        // Make sure the gl state is clean.
-        log_draw!("gl::BindBuffer(gl::ARRAY_BUFFER, buffer:0)");
-        gl::BindBuffer(gl::ARRAY_BUFFER, 0);
-
-        buffer
-    }
+pub fn create_buffer(backend: &dyn GlBackend, vertex_data: &[GLfloat]) -> GLuint {
+    log_draw!("    GLFloat size: {}", mem::size_of::<GLfloat>());
+    create_buffer_from_bytes(backend, unsafe { flatten(vertex_data) })
 }
 
-// TODO - De-duplicate this code so that it works with both GLfloat and GLuint.
-pub fn create_buffer_u32(vertex_data: &[GLuint]) -> GLuint {
-    unsafe {
-        // Create a vertex buffer object and copy the vertex data to it.
-        let mut buffer: GLuint = mem::uninitialized();
-        log_draw!("gl::GenBuffers(size:1, *buffer)");
-        gl::GenBuffers(1, &mut buffer);
-        log_draw!("    buffer -> {}", buffer);
-
-        log_draw!("gl::BindBuffer(gl::ARRAY_BUFFER, buffer:{:?})", buffer);
-        gl::BindBuffer(gl::ARRAY_BUFFER, buffer);
-
-        let size = (vertex_data.len() * mem::size_of::<GLuint>()) as GLsizeiptr;
-        log_draw!(
-            "gl::BufferData(gl::ARRAY_BUFFER, size:{:?}, *data, gl::STATIC_DRAW)",
-            size
-        );
-        // log_draw!("    vertex_data: {:?}", vertex_data);
-        log_draw!("    GLFloat size: {}", mem::size_of::<GLuint>());
-        gl::BufferData(
-            gl::ARRAY_BUFFER,
-            size,
-            mem::transmute(&vertex_data[0]),
-            gl::STATIC_DRAW
-        );
-
-        // Make sure the gl state is clean.
-        log_draw!("gl::BindBuffer(gl::ARRAY_BUFFER, buffer:0)");
-        gl::BindBuffer(gl::ARRAY_BUFFER, 0);
-
-        buffer
-    }
+pub fn create_buffer_u32(backend: &dyn GlBackend, vertex_data: &[GLuint]) -> GLuint {
+    log_draw!("    GLuint size: {}", mem::size_of::<GLuint>());
+    create_buffer_from_bytes(backend, unsafe { flatten(vertex_data) })
 }
 
-pub fn create_vao() -> GLuint {
-    unsafe {
-        // Create Vertex Array Object
-        let mut vao: GLuint = mem::uninitialized();
-        log_draw!("gl::GenVertexArrays(size:1, *vao)");
-        gl::GenVertexArrays(1, &mut vao);
-        log_draw!("    vao -> {}", vao);
-        vao
-    }
+pub fn create_buffer_from_bytes(backend: &dyn GlBackend, data: &[u8]) -> GLuint {
+    // Create a vertex buffer object and copy the vertex data to it.
+    log_draw!("gl::GenBuffers(size:1, *buffer)");
+    let buffer = backend.gen_buffer();
+    log_draw!("    buffer -> {}", buffer);
+
+    log_draw!("gl::BindBuffer(gl::ARRAY_BUFFER, buffer:{:?})", buffer);
+    backend.bind_buffer(gl::ARRAY_BUFFER, buffer);
+
+    log_draw!(
+        "gl::BufferData(gl::ARRAY_BUFFER, size:{:?}, *data, gl::STATIC_DRAW)",
+        data.len()
+    );
+    backend.buffer_data(gl::ARRAY_BUFFER, data, gl::STATIC_DRAW);
+
+    // Make sure the gl state is clean.
+    log_draw!("gl::BindBuffer(gl::ARRAY_BUFFER, buffer:0)");
+    backend.bind_buffer(gl::ARRAY_BUFFER, 0);
+
+    buffer
 }
 
-pub fn bind_vao(vao: GLuint) {
-    unsafe {
-        log_draw!("gl::BindVertexArray({})", vao);
-        gl::BindVertexArray(vao);
-    }
+pub fn create_vao(backend: &dyn GlBackend) -> GLuint {
+    // Create Vertex Array Object
+    log_draw!("gl::GenVertexArrays(size:1, *vao)");
+    let vao = backend.gen_vertex_array();
+    log_draw!("    vao -> {}", vao);
+    vao
+}
+
+pub fn bind_vao(backend: &dyn GlBackend, vao: GLuint) {
+    log_draw!("gl::BindVertexArray({})", vao);
+    backend.bind_vertex_array(vao);
+}
+
+pub fn delete_vao(backend: &dyn GlBackend, vao: GLuint) {
+    log_draw!("gl::DeleteVertexArrays(1, {})", vao);
+    backend.delete_vertex_array(vao);
 }
 
 pub fn bind_attribute_buffer(
-    vbo: GLuint,
+    backend: &dyn GlBackend,
+    attribute: &Attribute,
     attribute_info: &AttributeInfo
 ) {
-    unsafe {
-        // Bind the buffer of data that's going in that slot.
-        log_draw!("gl::BindBuffer(gl::ARRAY_BUFFER, {})", vbo);
-        gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
-
-        // Enable the slot in the shader for this attribute.
-        log_draw!("gl::EnableVertexAttribArray({})", attribute_info.index);
-        gl::EnableVertexAttribArray(attribute_info.index);
-
-        // Define how the pointers look up the information in the buffer.
-        log_draw!(
-            "gl::VertexAttribPointer(index:{}, size:{}, type:{}, normalize:gl::FALSE, stride:0,\
-            \n                        offset: ptr::null())",
-            attribute_info.index,
-            attribute_info.data_size,
-            gl_attribute_enum_to_string(attribute_info.data_type)
-        );
-        gl::VertexAttribPointer(
-            attribute_info.index,
-            attribute_info.data_size,
-            attribute_info.data_type,
-            gl::FALSE, // normalize
-            0, // stride
-            ptr::null() // offset
-            // (2 * mem::size_of::<f32>()) as *const () as *const _)
-        );
-    }
+    // Bind the buffer of data that's going in that slot.
+    log_draw!("gl::BindBuffer(gl::ARRAY_BUFFER, {})", attribute.buffer);
+    backend.bind_buffer(gl::ARRAY_BUFFER, attribute.buffer);
+
+    // Enable the slot in the shader for this attribute.
+    log_draw!("gl::EnableVertexAttribArray({})", attribute_info.index);
+    backend.enable_vertex_attrib_array(attribute_info.index);
+
+    // Define how the pointers look up the information in the buffer, anything that the
+    // attribute doesn't describe comes from the shader.
+    let size = attribute.components.unwrap_or(attribute_info.data_size);
+    let data_type = match attribute.component_type {
+        Some(component_type) => component_type.to_gl_enum(),
+        None => attribute_info.data_type
+    };
+
+    // Integer and double attributes in the shader need their own pointer functions, otherwise
+    // the GL converts the data to floats and the shader reads the bits of those floats.
+    match attribute_info.data_type {
+        gl::INT | gl::UNSIGNED_INT => {
+            match data_type {
+                gl::BYTE | gl::UNSIGNED_BYTE | gl::SHORT | gl::UNSIGNED_SHORT | gl::INT |
+                gl::UNSIGNED_INT => {},
+                _ => panic!(
+                    "The attribute {:?} is an integer in the shader, but its data is {}",
+                    attribute_info.name,
+                    gl_attribute_enum_to_string(data_type)
+                )
+            };
+            log_draw!(
+                "gl::VertexAttribIPointer(index:{}, size:{}, type:{}, stride:{}, offset:{})",
+                attribute_info.index,
+                size,
+                gl_attribute_enum_to_string(data_type),
+                attribute.stride,
+                attribute.offset
+            );
+            backend.vertex_attrib_i_pointer(
+                attribute_info.index,
+                size,
+                data_type,
+                attribute.stride,
+                attribute.offset
+            );
+        },
+        gl::DOUBLE => {
+            if data_type != gl::DOUBLE {
+                panic!(
+                    "The attribute {:?} is a double in the shader, but its data is {}",
+                    attribute_info.name,
+                    gl_attribute_enum_to_string(data_type)
+                );
+            }
+            log_draw!(
+                "gl::VertexAttribLPointer(index:{}, size:{}, type:{}, stride:{}, offset:{})",
+                attribute_info.index,
+                size,
+                gl_attribute_enum_to_string(data_type),
+                attribute.stride,
+                attribute.offset
+            );
+            backend.vertex_attrib_l_pointer(
+                attribute_info.index,
+                size,
+                data_type,
+                attribute.stride,
+                attribute.offset
+            );
+        },
+        _ => {
+            log_draw!(
+                "gl::VertexAttribPointer(index:{}, size:{}, type:{}, normalize:{}, stride:{},\
+                \n                        offset:{})",
+                attribute_info.index,
+                size,
+                gl_attribute_enum_to_string(data_type),
+                attribute.normalized,
+                attribute.stride,
+                attribute.offset
+            );
+            backend.vertex_attrib_pointer(
+                attribute_info.index,
+                size,
+                data_type,
+                attribute.normalized,
+                attribute.stride,
+                attribute.offset
+            );
+        }
+    };
 }
 
-pub fn get_attribute_count(program: GLuint) -> GLint {
-    unsafe {
-        // Get the count of attributes in our shader.
-        let mut attribute_count: GLint = mem::uninitialized();
-        gl::GetProgramiv(program, gl::ACTIVE_ATTRIBUTES, &mut attribute_count);
-        attribute_count
-    }
+/// Set the value that a disabled attribute reads for every vertex, with the function that
+/// matches the type of the attribute in the shader.
+pub fn set_attribute_constant(
+    backend: &dyn GlBackend,
+    index: GLuint,
+    data_type: GLenum,
+    value: [f32; 4]
+) {
+    match data_type {
+        gl::INT => {
+            log_draw!("gl::VertexAttribI4i({}, {:?})", index, value);
+            backend.vertex_attrib_i4i(
+                index,
+                value[0] as GLint,
+                value[1] as GLint,
+                value[2] as GLint,
+                value[3] as GLint
+            );
+        },
+        gl::UNSIGNED_INT => {
+            log_draw!("gl::VertexAttribI4ui({}, {:?})", index, value);
+            backend.vertex_attrib_i4ui(
+                index,
+                value[0] as GLuint,
+                value[1] as GLuint,
+                value[2] as GLuint,
+                value[3] as GLuint
+            );
+        },
+        _ => {
+            log_draw!("gl::VertexAttrib4f({}, {:?})", index, value);
+            backend.vertex_attrib_4f(index, value[0], value[1], value[2], value[3]);
+        }
+    };
 }
 
-pub fn get_uniform_count(program: GLuint) -> GLint {
-    unsafe {
-        // Get the count of uniforms in our shader.
-        let mut uniform_count: GLint = mem::uninitialized();
-        gl::GetProgramiv(program, gl::ACTIVE_UNIFORMS, &mut uniform_count);
-        uniform_count
+pub fn create_framebuffer(backend: &dyn GlBackend) -> GLuint {
+    log_draw!("gl::GenFramebuffers(size:1, *framebuffer)");
+    let framebuffer = backend.gen_framebuffer();
+    log_draw!("    framebuffer -> {}", framebuffer);
+    framebuffer
+}
+
+pub fn bind_framebuffer(backend: &dyn GlBackend, framebuffer: GLuint) {
+    log_draw!("gl::BindFramebuffer(gl::FRAMEBUFFER, {})", framebuffer);
+    backend.bind_framebuffer(gl::FRAMEBUFFER, framebuffer);
+}
+
+/// Create a renderbuffer with allocated storage, and attach it to the currently bound
+/// framebuffer.
+pub fn attach_renderbuffer(
+    backend: &dyn GlBackend,
+    attachment: GLenum,
+    internal_format: GLenum,
+    width: GLsizei,
+    height: GLsizei
+) -> GLuint {
+    log_draw!("gl::GenRenderbuffers(size:1, *renderbuffer)");
+    let renderbuffer = backend.gen_renderbuffer();
+    log_draw!("    renderbuffer -> {}", renderbuffer);
+
+    log_draw!("gl::BindRenderbuffer(gl::RENDERBUFFER, {})", renderbuffer);
+    backend.bind_renderbuffer(renderbuffer);
+
+    log_draw!(
+        "gl::RenderbufferStorage(gl::RENDERBUFFER, {}, width:{}, height:{})",
+        internal_format,
+        width,
+        height
+    );
+    backend.renderbuffer_storage(internal_format, width, height);
+
+    log_draw!(
+        "gl::FramebufferRenderbuffer(gl::FRAMEBUFFER, {}, gl::RENDERBUFFER, {})",
+        attachment,
+        renderbuffer
+    );
+    backend.framebuffer_renderbuffer(attachment, renderbuffer);
+
+    // Make sure the gl state is clean.
+    log_draw!("gl::BindRenderbuffer(gl::RENDERBUFFER, 0)");
+    backend.bind_renderbuffer(0);
+
+    renderbuffer
+}
+
+pub fn delete_framebuffer(backend: &dyn GlBackend, framebuffer: GLuint) {
+    log_draw!("gl::DeleteFramebuffers(1, {})", framebuffer);
+    backend.delete_framebuffer(framebuffer);
+}
+
+pub fn delete_renderbuffer(backend: &dyn GlBackend, renderbuffer: GLuint) {
+    log_draw!("gl::DeleteRenderbuffers(1, {})", renderbuffer);
+    backend.delete_renderbuffer(renderbuffer);
+}
+
+pub fn check_framebuffer_status(backend: &dyn GlBackend) {
+    let status = backend.check_framebuffer_status(gl::FRAMEBUFFER);
+    log_draw!("gl::CheckFramebufferStatus(gl::FRAMEBUFFER) -> {}", status);
+    if status != gl::FRAMEBUFFER_COMPLETE {
+        panic!("The framebuffer is not complete, status: {}", status);
     }
 }
 
+pub fn viewport(backend: &dyn GlBackend, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+    log_draw!("gl::Viewport({}, {}, {}, {})", x, y, width, height);
+    backend.viewport(x, y, width, height);
+}
+
+pub fn finish(backend: &dyn GlBackend) {
+    log_draw!("gl::Finish()");
+    backend.finish();
+}
+
+/// Read back a rectangle of the current read framebuffer as RGBA. Use `pixels::Pixels` to get
+/// the result as a typed image with the rows in the right order.
+pub fn read_pixels(
+    backend: &dyn GlBackend,
+    x: GLint,
+    y: GLint,
+    width: GLsizei,
+    height: GLsizei,
+    pixel_type: GLenum,
+    data: &mut [u8]
+) {
+    log_draw!("gl::ReadPixels({}, {}, {}, {}, gl::RGBA, {})", x, y, width, height, pixel_type);
+    backend.read_pixels(x, y, width, height, gl::RGBA, pixel_type, data);
+}
+
+pub fn get_attribute_count(backend: &dyn GlBackend, program: GLuint) -> GLint {
+    // Get the count of attributes in our shader.
+    backend.get_program_iv(program, gl::ACTIVE_ATTRIBUTES)
+}
+
+pub fn get_uniform_count(backend: &dyn GlBackend, program: GLuint) -> GLint {
+    // Get the count of uniforms in our shader.
+    backend.get_program_iv(program, gl::ACTIVE_UNIFORMS)
+}
+
 pub fn get_attribute_info(
+    backend: &dyn GlBackend,
     program: GLuint,
     attribute_index: GLint
 ) -> AttributeInfo {
-    unsafe {
-        let max_name_length: GLsizei = 127;
-        let mut name_buffer: Vec<u8> = Vec::with_capacity(128);
-
-        // Write the attribute information into these values.
-        let mut name_length: GLsizei = mem::uninitialized();
-        let mut data_size: GLint = mem::uninitialized();
-        let mut data_type: GLenum = mem::uninitialized();
-
-        log_draw!(
-            "gl::GetActiveAttrib(program:{}, attribute:{}, max_name_length:{}, *name_length, \
-            \n                    *data_size, *data_type, *name_buffer)",
-            program,
-            attribute_index as GLuint,
-            max_name_length
-        );
-        gl::GetActiveAttrib(
-            program,
-            attribute_index as GLuint,
-            max_name_length,
-            &mut name_length,
-            &mut data_size,
-            &mut data_type,
-            name_buffer.as_mut_ptr() as *mut gl::types::GLchar
-        );
-
-        name_buffer.set_len(name_length as usize);
-        let name = String::from_utf8(name_buffer).unwrap();
-        log_draw!("    name -> {:?}", name);
-        log_draw!("    name_length -> {}", name_length);
-        log_draw!("    data_size -> {}", data_size);
-        log_draw!("    data_type -> {}", gl_attribute_enum_to_string(data_type));
-
-        let info = AttributeInfo {
-            name: name,
-            index: attribute_index as GLuint,
-            data_type: get_attribute_type(data_type),
-            data_size: get_attribute_type_size(data_type),
-            type_enum: data_type
-        };
-
-        // log_draw!("{:?}", info);
-
-        info
+    log_draw!(
+        "gl::GetActiveAttrib(program:{}, attribute:{}, max_name_length:127, *name_length, \
+        \n                    *data_size, *data_type, *name_buffer)",
+        program,
+        attribute_index as GLuint
+    );
+    let active_info = backend.get_active_attrib(program, attribute_index as GLuint);
+    log_draw!("    name -> {:?}", active_info.name);
+    log_draw!("    data_size -> {}", active_info.data_size);
+    log_draw!("    data_type -> {}", gl_attribute_enum_to_string(active_info.data_type));
+
+    AttributeInfo {
+        name: active_info.name,
+        index: attribute_index as GLuint,
+        data_type: get_attribute_type(active_info.data_type),
+        data_size: get_attribute_type_size(active_info.data_type),
+        type_enum: active_info.data_type
     }
 }
 
 pub fn get_uniform_info(
+    backend: &dyn GlBackend,
     program: GLuint,
     uniform_index: GLint
 ) -> UniformInfo {
-    unsafe {
-        let max_name_length: GLsizei = 127;
-        let mut name_buffer: Vec<u8> = Vec::with_capacity(128);
-
-        // Write the uniform information into these values.
-        let mut name_length: GLsizei = mem::uninitialized();
-        let mut data_size: GLsizei = mem::uninitialized();
-        let mut data_type: GLenum = mem::uninitialized();
-
-        log_draw!(
-            "gl::GetActiveUniform(program:{}, uniform:{}, max_name_length:{}, *name_length, \
-            \n                    *data_size, *data_type, *name_buffer)",
-            program,
-            uniform_index as GLuint,
-            max_name_length
-        );
-
-        gl::GetActiveUniform(
-            program,
-            uniform_index as GLuint,
-            max_name_length,
-            &mut name_length,
-            &mut data_size,
-            &mut data_type,
-            name_buffer.as_mut_ptr() as *mut gl::types::GLchar
-        );
-
-        // Pass a pointer to the name buffer, which should be a null-terminated string.
-        let location = gl::GetUniformLocation(program, name_buffer.as_mut_ptr() as *const GLchar);
-
-        // Extract the name as a Rust-native UTF-8 string.
-        name_buffer.set_len(name_length as usize);
-        let name = String::from_utf8(name_buffer).unwrap();
-
-        log_draw!(
-            "gl::GetUniformLocation(program:{}, name:\"{}\") -> {:?}",
-            program,
-            name,
-            location
-        );
-        log_draw!("    name -> {:?}", name);
-        log_draw!("    name_length -> {}", name_length);
-        log_draw!("    data_size -> {}", data_size);
-        log_draw!("    data_type -> {}", gl_attribute_enum_to_string(data_type));
-
-        let info = UniformInfo {
-            name: name,
-            index: uniform_index as GLuint,
-            location: location,
-            data_type: data_type,
-            data_size: data_size
-        };
-
-        info
+    log_draw!(
+        "gl::GetActiveUniform(program:{}, uniform:{}, max_name_length:127, *name_length, \
+        \n                    *data_size, *data_type, *name_buffer)",
+        program,
+        uniform_index as GLuint
+    );
+    let active_info = backend.get_active_uniform(program, uniform_index as GLuint);
+
+    let location = backend.get_uniform_location(program, &active_info.name);
+
+    log_draw!(
+        "gl::GetUniformLocation(program:{}, name:\"{}\") -> {:?}",
+        program,
+        active_info.name,
+        location
+    );
+    log_draw!("    name -> {:?}", active_info.name);
+    log_draw!("    data_size -> {}", active_info.data_size);
+    log_draw!("    data_type -> {}", gl_attribute_enum_to_string(active_info.data_type));
+
+    UniformInfo {
+        name: active_info.name,
+        index: uniform_index as GLuint,
+        location,
+        data_type: active_info.data_type,
+        data_size: active_info.data_size
     }
 }
 
-pub fn get_program_attributes(program: GLuint) -> Vec<AttributeInfo> {
+pub fn get_program_attributes(backend: &dyn GlBackend, program: GLuint) -> Vec<AttributeInfo> {
     let mut attributes: Vec<AttributeInfo> = Vec::new();
-    let attribute_count = get_attribute_count(program);
+    let attribute_count = get_attribute_count(backend, program);
     for attribute_index in 0..attribute_count {
-        let attribute_info = get_attribute_info(program, attribute_index);
+        let attribute_info = get_attribute_info(backend, program, attribute_index);
         if attribute_info.name.starts_with("gl_") {
             continue;
         }
@@ -393,11 +484,11 @@ pub fn get_program_attributes(program: GLuint) -> Vec<AttributeInfo> {
     attributes
 }
 
-pub fn get_uniforms(program: GLuint) -> Vec<UniformInfo> {
+pub fn get_uniforms(backend: &dyn GlBackend, program: GLuint) -> Vec<UniformInfo> {
     let mut uniforms: Vec<UniformInfo> = Vec::new();
-    let uniform_count = get_uniform_count(program);
+    let uniform_count = get_uniform_count(backend, program);
     for uniform_index in 0..uniform_count {
-        let uniform_info = get_uniform_info(program, uniform_index);
+        let uniform_info = get_uniform_info(backend, program, uniform_index);
         uniforms.push(uniform_info);
     }
     uniforms
@@ -483,50 +574,70 @@ pub fn get_attribute_type(data_type: GLenum) -> GLenum {
     }
 }
 
-pub fn draw_arrays(mode: GLenum, start: GLint, count: GLsizei) {
-    unsafe {
-        log_draw!(
-            "gl::DrawArrays({}, {}, {:?})",
-            gl_draw_mode_enum_to_string(mode),
-            start,
-            count
-        );
-        gl::DrawArrays(mode, start, count)
-    }
+pub fn draw_arrays(backend: &dyn GlBackend, mode: GLenum, start: GLint, count: GLsizei) {
+    log_draw!(
+        "gl::DrawArrays({}, {}, {:?})",
+        gl_draw_mode_enum_to_string(mode),
+        start,
+        count
+    );
+    backend.draw_arrays(mode, start, count);
 }
 
-pub fn draw_elements(mode: GLenum, count: GLsizei) {
-    unsafe {
-        log_draw!(
-            "gl::DrawElements({}, count:{:?}, gl::UNSIGNED_INT, offset:{:?})",
-            gl_draw_mode_enum_to_string(mode),
-            count,
-            0
-        );
-        gl::DrawElements(
-            mode,
-            count,
-            gl::UNSIGNED_INT,
-            ptr::null_mut()
-        );
-    }
+/// Draw `count` elements from the bound element buffer, starting `first` elements in.
+pub fn draw_elements(backend: &dyn GlBackend, mode: GLenum, first: GLint, count: GLsizei) {
+    let offset = first as usize * mem::size_of::<GLuint>();
+    log_draw!(
+        "gl::DrawElements({}, count:{:?}, gl::UNSIGNED_INT, offset:{:?})",
+        gl_draw_mode_enum_to_string(mode),
+        count,
+        offset
+    );
+    backend.draw_elements(mode, count, gl::UNSIGNED_INT, offset);
 }
 
+pub fn draw_arrays_instanced(
+    backend: &dyn GlBackend,
+    mode: GLenum,
+    start: GLint,
+    count: GLsizei,
+    instances: GLsizei
+) {
+    log_draw!(
+        "gl::DrawArraysInstanced({}, {}, {:?}, instances:{:?})",
+        gl_draw_mode_enum_to_string(mode),
+        start,
+        count,
+        instances
+    );
+    backend.draw_arrays_instanced(mode, start, count, instances);
+}
 
-pub fn get_major_version() -> i32 {
-    unsafe {
-        let mut major: GLint = mem::uninitialized();
-        gl::GetIntegerv(gl::MAJOR_VERSION, &mut major);
-        major as i32
-    }
+pub fn draw_elements_instanced(
+    backend: &dyn GlBackend,
+    mode: GLenum,
+    first: GLint,
+    count: GLsizei,
+    instances: GLsizei
+) {
+    let offset = first as usize * mem::size_of::<GLuint>();
+    log_draw!(
+        "gl::DrawElementsInstanced({}, count:{:?}, gl::UNSIGNED_INT, offset:{:?}, instances:{:?})",
+        gl_draw_mode_enum_to_string(mode),
+        count,
+        offset,
+        instances
+    );
+    backend.draw_elements_instanced(mode, count, gl::UNSIGNED_INT, offset, instances);
 }
 
-pub fn get_minor_version() -> i32 {
-    unsafe {
-        let mut minor: GLint = mem::uninitialized();
-        gl::GetIntegerv(gl::MINOR_VERSION, &mut minor);
-        minor as i32
-    }
+
+pub fn get_major_version(backend: &dyn GlBackend) -> i32 {
+    backend.get_integer_v(gl::MAJOR_VERSION)
+}
+
+pub fn get_minor_version(backend: &dyn GlBackend) -> i32 {
+    backend.get_integer_v(gl::MINOR_VERSION)
 }
 
 pub fn gl_attribute_enum_to_string(data_type: GLenum) -> &'static str {
@@ -565,6 +676,11 @@ pub fn gl_attribute_enum_to_string(data_type: GLenum) -> &'static str {
         gl::DOUBLE_MAT3x4 => "gl::DOUBLE_MAT3x4",
         gl::DOUBLE_MAT4x2 => "gl::DOUBLE_MAT4x2",
         gl::DOUBLE_MAT4x3 => "gl::DOUBLE_MAT4x3",
+        gl::BYTE => "gl::BYTE",
+        gl::UNSIGNED_BYTE => "gl::UNSIGNED_BYTE",
+        gl::SHORT => "gl::SHORT",
+        gl::UNSIGNED_SHORT => "gl::UNSIGNED_SHORT",
+        gl::HALF_FLOAT => "gl::HALF_FLOAT",
         _ => panic!("Unknown gl attribute enum.")
     }
 }
@@ -0,0 +1,166 @@
+use super::glutin;
+use std::collections::HashSet;
+
+pub use super::glutin::{MouseButton, VirtualKeyCode};
+
+/// The state of the mouse, keyboard and window focus, which is updated from the window events
+/// at the start of every tick. Anything ending in `_pressed` is only set on the tick where the
+/// button or key went down.
+pub struct Input {
+    /// The cursor position in pixels, relative to the top left of the window.
+    pub cursor: (f32, f32),
+    /// The cursor position from -1 to 1 with the y axis pointing up, which matches the
+    /// normalized device coordinates of the viewport.
+    pub cursor_normalized: (f32, f32),
+    /// The mouse buttons that are currently held down.
+    pub mouse_buttons: HashSet<MouseButton>,
+    /// The mouse buttons that went down during this tick.
+    pub mouse_buttons_pressed: HashSet<MouseButton>,
+    /// How far the wheel scrolled during this tick, in lines.
+    pub wheel_delta: (f32, f32),
+    /// The keys that are currently held down.
+    pub keys: HashSet<VirtualKeyCode>,
+    /// The keys that went down during this tick.
+    pub keys_pressed: HashSet<VirtualKeyCode>,
+    pub focused: bool
+}
+
+// Pixel deltas from touchpads are converted to lines with this size, so that the wheel delta is
+// consistent across devices.
+const PIXELS_PER_LINE: f32 = 16.0;
+
+impl Default for Input {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Input {
+    pub fn new() -> Input {
+        Input {
+            cursor: (0.0, 0.0),
+            cursor_normalized: (0.0, 0.0),
+            mouse_buttons: HashSet::new(),
+            mouse_buttons_pressed: HashSet::new(),
+            wheel_delta: (0.0, 0.0),
+            keys: HashSet::new(),
+            keys_pressed: HashSet::new(),
+            focused: true
+        }
+    }
+
+    pub fn is_key_down(&self, key: VirtualKeyCode) -> bool {
+        self.keys.contains(&key)
+    }
+
+    pub fn was_key_pressed(&self, key: VirtualKeyCode) -> bool {
+        self.keys_pressed.contains(&key)
+    }
+
+    pub fn is_mouse_down(&self, button: MouseButton) -> bool {
+        self.mouse_buttons.contains(&button)
+    }
+
+    pub fn was_mouse_pressed(&self, button: MouseButton) -> bool {
+        self.mouse_buttons_pressed.contains(&button)
+    }
+
+    /// Forget everything that only applies to a single tick.
+    pub fn start_tick(&mut self) {
+        self.mouse_buttons_pressed.clear();
+        self.keys_pressed.clear();
+        self.wheel_delta = (0.0, 0.0);
+    }
+
+    /// Update the input from a window event. The viewport size is used to compute the
+    /// normalized cursor position.
+    pub fn handle_event(
+        &mut self,
+        event: &glutin::WindowEvent,
+        viewport_width: u32,
+        viewport_height: u32
+    ) {
+        match *event {
+            glutin::WindowEvent::MouseMoved(x, y) => {
+                self.cursor = (x as f32, y as f32);
+                self.cursor_normalized = (
+                    2.0 * x as f32 / (viewport_width.max(1) as f32) - 1.0,
+                    1.0 - 2.0 * y as f32 / (viewport_height.max(1) as f32)
+                );
+            },
+            glutin::WindowEvent::MouseInput(state, button) => {
+                match state {
+                    glutin::ElementState::Pressed => {
+                        self.mouse_buttons.insert(button);
+                        self.mouse_buttons_pressed.insert(button);
+                    },
+                    glutin::ElementState::Released => {
+                        self.mouse_buttons.remove(&button);
+                    }
+                };
+            },
+            glutin::WindowEvent::MouseWheel(delta, ..) => {
+                let (x, y) = match delta {
+                    glutin::MouseScrollDelta::LineDelta(x, y) => (x, y),
+                    glutin::MouseScrollDelta::PixelDelta(x, y) => {
+                        (x / PIXELS_PER_LINE, y / PIXELS_PER_LINE)
+                    }
+                };
+                self.wheel_delta = (self.wheel_delta.0 + x, self.wheel_delta.1 + y);
+            },
+            glutin::WindowEvent::KeyboardInput(state, _, Some(key), ..) => {
+                match state {
+                    glutin::ElementState::Pressed => {
+                        // Key repeats send multiple presses, only the first one counts.
+                        if self.keys.insert(key) {
+                            self.keys_pressed.insert(key);
+                        }
+                    },
+                    glutin::ElementState::Released => {
+                        self.keys.remove(&key);
+                    }
+                };
+            },
+            glutin::WindowEvent::Focused(focused) => {
+                self.focused = focused;
+                if !focused {
+                    // Releases won't be delivered to an unfocused window.
+                    self.keys.clear();
+                    self.mouse_buttons.clear();
+                }
+            },
+            _ => {}
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::glutin::{ElementState, WindowEvent};
+
+    #[test]
+    fn cursor_is_normalized_to_the_viewport() {
+        let mut input = Input::new();
+        input.handle_event(&WindowEvent::MouseMoved(250, 150), 1000, 600);
+
+        assert_eq!(input.cursor, (250.0, 150.0));
+        assert_eq!(input.cursor_normalized, (-0.5, 0.5));
+    }
+
+    #[test]
+    fn presses_only_last_for_a_tick() {
+        let mut input = Input::new();
+        input.handle_event(&WindowEvent::MouseInput(ElementState::Pressed, MouseButton::Left), 1000, 600);
+
+        assert!(input.is_mouse_down(MouseButton::Left));
+        assert!(input.was_mouse_pressed(MouseButton::Left));
+
+        input.start_tick();
+        assert!(input.is_mouse_down(MouseButton::Left));
+        assert!(!input.was_mouse_pressed(MouseButton::Left));
+
+        input.handle_event(&WindowEvent::MouseInput(ElementState::Released, MouseButton::Left), 1000, 600);
+        assert!(!input.is_mouse_down(MouseButton::Left));
+    }
+}
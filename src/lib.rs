@@ -4,18 +4,17 @@ extern crate glutin;
 
 macro_rules! log_draw {
     ($($arg:tt)*) => {
-        {
-            #[cfg(feature = "log_draw")]
+        if cfg!(feature = "log_draw") {
             println!($($arg)*);
         }
     };
 }
 
 macro_rules! check_gl_errors {
-    ($($arg:tt)*) => {
+    ($backend:expr) => {
         {
             #[cfg(feature = "log_draw")] {
-                let error = unsafe { gl::GetError() };
+                let error = $backend.get_error();
 
                 match error {
                     gl::INVALID_ENUM => {
@@ -57,13 +56,26 @@ macro_rules! check_gl_errors {
     };
 }
 
+pub mod backend;
+pub mod recording_backend;
+pub mod caching_backend;
+#[macro_use]
+pub mod attributes;
 pub mod draw_builder;
 #[macro_use]
 pub mod clear;
 pub mod gl_helpers;
+pub mod input;
 pub mod rugl;
 pub mod buffers;
+pub mod framebuffer;
 pub mod uniforms;
+pub mod offline;
+pub mod pixels;
+pub mod scope;
+pub mod state;
 mod primitive;
+mod clock;
 pub use primitive::Primitive;
+pub use clock::Clock;
 pub use rugl::*;
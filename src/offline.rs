@@ -0,0 +1,180 @@
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// What to render with `Rugl::render()`. Every frame is drawn into an offscreen framebuffer
+/// of the given size, with the clock advancing exactly `1 / fps` seconds per frame, and then
+/// written out as a numbered PNG.
+///
+///     use rugl::offline::RenderSettings;
+///
+///     let settings = RenderSettings::new(1920, 1080, 300)
+///         .fps(60)
+///         .output("frames/teapot-")
+///         .y4m("teapot.y4m");
+///
+///     // rugl.render(&settings, |env| { clear(); draw(env); }).unwrap();
+///
+/// This writes `frames/teapot-00000.png` to `frames/teapot-00299.png`, and the same frames as
+/// a single uncompressed video stream to `teapot.y4m`.
+pub struct RenderSettings {
+    pub width: u32,
+    pub height: u32,
+    pub frames: u32,
+    pub fps: u32,
+    /// The start of the path of every PNG, the frame number and extension are appended.
+    pub output: PathBuf,
+    /// Where to write a YUV4MPEG2 video of the frames, if anywhere.
+    pub y4m: Option<PathBuf>
+}
+
+impl RenderSettings {
+    pub fn new(width: u32, height: u32, frames: u32) -> RenderSettings {
+        RenderSettings {
+            width,
+            height,
+            frames,
+            fps: 60,
+            output: PathBuf::from("frame-"),
+            y4m: None
+        }
+    }
+
+    pub fn fps(mut self, fps: u32) -> RenderSettings {
+        self.fps = fps;
+        self
+    }
+
+    pub fn output<P: AsRef<Path>>(mut self, output: P) -> RenderSettings {
+        self.output = output.as_ref().to_path_buf();
+        self
+    }
+
+    pub fn y4m<P: AsRef<Path>>(mut self, path: P) -> RenderSettings {
+        self.y4m = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// The path of the PNG for a frame, e.g. `frame-00012.png`.
+    pub fn frame_path(&self, frame: u32) -> PathBuf {
+        let mut path = self.output.clone().into_os_string();
+        path.push(format!("{:05}.png", frame));
+        PathBuf::from(path)
+    }
+
+    /// Make sure the directories that the frames are written into exist.
+    pub fn create_directories(&self) -> io::Result<()> {
+        let mut paths = vec![self.frame_path(0)];
+        if let Some(ref y4m) = self.y4m {
+            paths.push(y4m.clone());
+        }
+        for path in paths {
+            match path.parent() {
+                Some(parent) if parent != Path::new("") => fs::create_dir_all(parent)?,
+                _ => {}
+            };
+        }
+        Ok(())
+    }
+}
+
+/// Writes frames to an uncompressed YUV4MPEG2 stream with full resolution chroma, which
+/// ffmpeg and most video players can read directly, e.g.
+/// `ffmpeg -i teapot.y4m -pix_fmt yuv420p teapot.mp4`.
+pub struct Y4mWriter {
+    width: u32,
+    height: u32,
+    writer: BufWriter<File>
+}
+
+impl Y4mWriter {
+    pub fn create<P: AsRef<Path>>(path: P, width: u32, height: u32, fps: u32) -> io::Result<Y4mWriter> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "YUV4MPEG2 W{} H{} F{}:1 Ip A1:1 C444", width, height, fps)?;
+
+        Ok(Y4mWriter {
+            width,
+            height,
+            writer
+        })
+    }
+
+    /// Write a frame of 8 bit RGBA pixels, ordered from the top row to the bottom. The alpha
+    /// channel is dropped.
+    pub fn write_frame(&mut self, data: &[u8]) -> io::Result<()> {
+        let pixel_count = (self.width * self.height) as usize;
+        assert_eq!(data.len(), pixel_count * 4, "The frame doesn't match the video size");
+
+        let mut planes = vec![0u8; pixel_count * 3];
+        for (index, pixel) in data.chunks(4).enumerate() {
+            let (y, cb, cr) = rgb_to_ycbcr(pixel[0], pixel[1], pixel[2]);
+            planes[index] = y;
+            planes[pixel_count + index] = cb;
+            planes[pixel_count * 2 + index] = cr;
+        }
+
+        self.writer.write_all(b"FRAME\n")?;
+        self.writer.write_all(&planes)
+    }
+
+    pub fn finish(mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Convert to the limited range BT.601 color space that y4m readers assume by default.
+fn rgb_to_ycbcr(red: u8, green: u8, blue: u8) -> (u8, u8, u8) {
+    let r = red as f32 / 255.0;
+    let g = green as f32 / 255.0;
+    let b = blue as f32 / 255.0;
+
+    let y = 16.0 + 65.481 * r + 128.553 * g + 24.966 * b;
+    let cb = 128.0 - 37.797 * r - 74.203 * g + 112.0 * b;
+    let cr = 128.0 + 112.0 * r - 93.786 * g - 18.214 * b;
+
+    (y.round() as u8, cb.round() as u8, cr.round() as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::recording_backend::RecordingBackend;
+    use super::super::rugl::init_with_backend;
+    use std::env;
+    use std::rc::Rc;
+
+    #[test]
+    fn frames_are_numbered_after_the_output_prefix() {
+        let settings = RenderSettings::new(640, 480, 10).output("frames/teapot-");
+        assert_eq!(settings.frame_path(7), PathBuf::from("frames/teapot-00007.png"));
+    }
+
+    #[test]
+    fn colors_convert_to_limited_range() {
+        assert_eq!(rgb_to_ycbcr(0, 0, 0), (16, 128, 128));
+        assert_eq!(rgb_to_ycbcr(255, 255, 255), (235, 128, 128));
+    }
+
+    #[test]
+    fn every_render_deletes_its_framebuffer() {
+        let backend = Rc::new(RecordingBackend::new());
+        let mut rugl = init_with_backend(backend.clone());
+        let output = env::temp_dir().join("rugl-offline-test").join("frame-");
+        let settings = RenderSettings::new(4, 4, 2).output(&output);
+
+        for _ in 0..2 {
+            rugl.render(&settings, |_| {}).unwrap();
+        }
+
+        let count = |prefix: &str| {
+            backend.calls().iter().filter(|call| call.starts_with(prefix)).count()
+        };
+        assert_eq!(count("GenFramebuffers"), 2);
+        assert_eq!(count("DeleteFramebuffers"), 2);
+        // Nothing is drawn after the framebuffer is gone.
+        assert!(backend.calls().last().unwrap().starts_with("DeleteRenderbuffers"));
+        fs::remove_dir_all(output.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,290 @@
+extern crate png;
+use self::png::HasParameters;
+use super::backend::GlBackend;
+use super::gl;
+use super::gl::types::*;
+use super::gl_helpers;
+use super::rugl::Environment;
+use std::fs::File;
+use std::io;
+use std::io::BufWriter;
+use std::path::Path;
+use std::rc::Rc;
+use std::slice;
+
+/// The type of each channel when reading pixels back from the GL. The channels are always
+/// red, green, blue and alpha.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PixelFormat {
+    /// 8 bits per channel, from 0 to 255.
+    Rgba8,
+    /// A float per channel, which keeps values outside of 0 to 1 when reading from a float
+    /// render target.
+    RgbaF32
+}
+
+impl PixelFormat {
+    pub fn to_gl_enum(&self) -> GLenum {
+        match *self {
+            PixelFormat::Rgba8 => gl::UNSIGNED_BYTE,
+            PixelFormat::RgbaF32 => gl::FLOAT
+        }
+    }
+}
+
+pub enum PixelData {
+    Rgba8(Vec<u8>),
+    RgbaF32(Vec<f32>)
+}
+
+/// An image read back from the GL. The rows are ordered from the top of the image to the
+/// bottom, so `data` can be handed straight to image libraries.
+pub struct Pixels {
+    pub width: u32,
+    pub height: u32,
+    pub data: PixelData
+}
+
+impl Pixels {
+    /// Read a rectangle of the current read framebuffer, which is `target_width` by
+    /// `target_height` pixels. The `x` and `y` are measured from the top left, the same as
+    /// `input.cursor`. This panics if the rectangle doesn't fit inside of the target.
+    // The rectangle and the size of its target are passed separately, the same as glReadPixels.
+    #[allow(clippy::too_many_arguments)]
+    pub fn read(
+        backend: &dyn GlBackend,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        target_width: u32,
+        target_height: u32,
+        format: PixelFormat
+    ) -> Pixels {
+        if x as u64 + width as u64 > target_width as u64 ||
+            y as u64 + height as u64 > target_height as u64
+        {
+            panic!(
+                "Can't read {}x{} pixels at ({}, {}) from a {}x{} render target.",
+                width, height, x, y, target_width, target_height
+            );
+        }
+
+        // The GL measures from the bottom left.
+        let gl_y = target_height as GLint - y as GLint - height as GLint;
+        let row_length = width as usize * 4;
+        let length = row_length * height as usize;
+
+        let data = match format {
+            PixelFormat::Rgba8 => {
+                let mut data = vec![0u8; length];
+                gl_helpers::read_pixels(
+                    backend, x as GLint, gl_y, width as GLsizei, height as GLsizei,
+                    format.to_gl_enum(), &mut data
+                );
+                PixelData::Rgba8(flip_rows(&data, row_length))
+            },
+            PixelFormat::RgbaF32 => {
+                let mut data = vec![0f32; length];
+                {
+                    let bytes = unsafe {
+                        slice::from_raw_parts_mut(data.as_mut_ptr() as *mut u8, length * 4)
+                    };
+                    gl_helpers::read_pixels(
+                        backend, x as GLint, gl_y, width as GLsizei, height as GLsizei,
+                        format.to_gl_enum(), bytes
+                    );
+                }
+                PixelData::RgbaF32(flip_rows(&data, row_length))
+            }
+        };
+
+        Pixels {
+            width,
+            height,
+            data
+        }
+    }
+
+    pub fn format(&self) -> PixelFormat {
+        match self.data {
+            PixelData::Rgba8(_) => PixelFormat::Rgba8,
+            PixelData::RgbaF32(_) => PixelFormat::RgbaF32
+        }
+    }
+
+    /// The pixel at `x` and `y` from the top left, with the channels from 0 to 1. This panics
+    /// if the pixel is outside of the image.
+    pub fn get(&self, x: u32, y: u32) -> [f32; 4] {
+        if x >= self.width || y >= self.height {
+            panic!(
+                "The pixel ({}, {}) is outside of the {}x{} image.",
+                x, y, self.width, self.height
+            );
+        }
+        let index = ((y * self.width + x) * 4) as usize;
+        match self.data {
+            PixelData::Rgba8(ref data) => [
+                data[index] as f32 / 255.0,
+                data[index + 1] as f32 / 255.0,
+                data[index + 2] as f32 / 255.0,
+                data[index + 3] as f32 / 255.0
+            ],
+            PixelData::RgbaF32(ref data) => [
+                data[index],
+                data[index + 1],
+                data[index + 2],
+                data[index + 3]
+            ]
+        }
+    }
+
+    /// Convert to 8 bit channels, float channels are clamped to 0 to 1.
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        match self.data {
+            PixelData::Rgba8(ref data) => data.clone(),
+            PixelData::RgbaF32(ref data) => {
+                data.iter().map(|value| {
+                    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+                }).collect()
+            }
+        }
+    }
+
+    /// Write the pixels to a PNG file, float channels are clamped to 0 to 1.
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let file = File::create(path)?;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), self.width, self.height);
+        encoder.set(png::ColorType::RGBA).set(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(to_io_error)?;
+        writer.write_image_data(&self.to_rgba8()).map_err(to_io_error)
+    }
+}
+
+/// Reads back what has been drawn into the window from inside of the frame closure, which
+/// is the only time that the back buffer holds the frame. It's created with
+/// `Rugl::pixel_reader()`.
+///
+///     use rugl::Rugl;
+///     use rugl::pixels::{PixelFormat, Pixels};
+///
+///     fn pick(rugl: &mut Rugl) -> Option<Pixels> {
+///         let reader = rugl.pixel_reader();
+///         let mut picked = None;
+///         rugl.frame(|env| {
+///             // Draw the scene with an id color for every object, then read under the cursor.
+///             let (x, y) = (env.input.cursor.0 as u32, env.input.cursor.1 as u32);
+///             if x < env.viewport_width && y < env.viewport_height {
+///                 picked = Some(reader.read(env, x, y, 1, 1, PixelFormat::Rgba8));
+///             }
+///         });
+///         picked
+///     }
+///
+#[derive(Clone)]
+pub struct PixelReader {
+    backend: Rc<dyn GlBackend>
+}
+
+impl PixelReader {
+    pub fn new(backend: Rc<dyn GlBackend>) -> PixelReader {
+        PixelReader {
+            backend
+        }
+    }
+
+    /// Read a rectangle of the frame being drawn, with `x` and `y` measured in pixels from
+    /// the top left of the window's drawing buffer. This panics if the rectangle doesn't fit
+    /// inside of the drawing buffer.
+    pub fn read(
+        &self,
+        environment: &Environment,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        format: PixelFormat
+    ) -> Pixels {
+        Pixels::read(
+            &*self.backend,
+            x,
+            y,
+            width,
+            height,
+            environment.viewport_width,
+            environment.viewport_height,
+            format
+        )
+    }
+}
+
+fn to_io_error(error: png::EncodingError) -> io::Error {
+    io::Error::other(error.to_string())
+}
+
+fn flip_rows<T: Clone>(data: &[T], row_length: usize) -> Vec<T> {
+    let mut flipped = Vec::with_capacity(data.len());
+    for row in data.chunks(row_length.max(1)).rev() {
+        flipped.extend_from_slice(row);
+    }
+    flipped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::recording_backend::RecordingBackend;
+    use super::super::rugl::init_with_backend;
+
+    #[test]
+    fn rows_are_flipped_to_top_down() {
+        assert_eq!(flip_rows(&[1, 2, 3, 4, 5, 6], 2), vec![5, 6, 3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn reads_are_measured_from_the_top_left() {
+        let backend = RecordingBackend::new();
+        let pixels = Pixels::read(&backend, 10, 20, 30, 40, 800, 600, PixelFormat::RgbaF32);
+
+        assert_eq!(
+            backend.calls(),
+            vec![format!("ReadPixels(10, 540, 30, 40, {}, {})", gl::RGBA, gl::FLOAT)]
+        );
+        assert_eq!(pixels.format(), PixelFormat::RgbaF32);
+        assert_eq!(pixels.get(29, 39), [0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't read 30x40 pixels at (10, 570) from a 800x600")]
+    fn reads_outside_of_the_target_are_rejected() {
+        let backend = RecordingBackend::new();
+        Pixels::read(&backend, 10, 570, 30, 40, 800, 600, PixelFormat::Rgba8);
+    }
+
+    #[test]
+    #[should_panic(expected = "The pixel (30, 0) is outside of the 30x40 image.")]
+    fn pixels_outside_of_the_image_are_rejected() {
+        let backend = RecordingBackend::new();
+        let pixels = Pixels::read(&backend, 0, 0, 30, 40, 800, 600, PixelFormat::Rgba8);
+        pixels.get(30, 0);
+    }
+
+    #[test]
+    fn the_frame_can_be_read_inside_of_the_frame_closure() {
+        let backend = Rc::new(RecordingBackend::new());
+        let mut rugl = init_with_backend(backend.clone());
+        let reader = rugl.pixel_reader();
+
+        let mut pixels = None;
+        backend.clear_calls();
+        rugl.frame(|env| {
+            pixels = Some(reader.read(env, 0, 0, 2, 2, PixelFormat::Rgba8));
+        });
+
+        assert_eq!(pixels.map(|pixels| pixels.width), Some(2));
+        assert!(backend.calls().contains(
+            &format!("ReadPixels(0, 598, 2, 2, {}, {})", gl::RGBA, gl::UNSIGNED_BYTE)
+        ));
+    }
+}
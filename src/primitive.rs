@@ -13,14 +13,14 @@ pub enum Primitive {
 
 impl Primitive {
     pub fn to_gl_enum(&self) -> GLenum {
-        match self {
-            &Primitive::Points => gl::POINTS,
-            &Primitive::Lines => gl::LINES,
-            &Primitive::LineStrip => gl::LINE_STRIP,
-            &Primitive::LineLoop => gl::LINE_LOOP,
-            &Primitive::Triangles => gl::TRIANGLES,
-            &Primitive::TriangleStrip => gl::TRIANGLE_STRIP,
-            &Primitive::TriangleFan => gl::TRIANGLE_FAN
+        match *self {
+            Primitive::Points => gl::POINTS,
+            Primitive::Lines => gl::LINES,
+            Primitive::LineStrip => gl::LINE_STRIP,
+            Primitive::LineLoop => gl::LINE_LOOP,
+            Primitive::Triangles => gl::TRIANGLES,
+            Primitive::TriangleStrip => gl::TRIANGLE_STRIP,
+            Primitive::TriangleFan => gl::TRIANGLE_FAN
         }
     }
 }
@@ -0,0 +1,627 @@
+use super::backend::{ActiveInfo, GlBackend};
+use super::gl;
+use super::gl::types::*;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+/// A `GlBackend` that doesn't talk to a driver. Every call is recorded as a string, e.g.
+/// `"Clear(16384)"`, so that tests can assert on what a command did. Object names are handed
+/// out sequentially, and programs report the attributes and uniforms that are declared in
+/// their shader sources.
+///
+///     use rugl::recording_backend::RecordingBackend;
+///     use std::rc::Rc;
+///
+///     let backend = Rc::new(RecordingBackend::new());
+///     let rugl = rugl::init_with_backend(backend.clone());
+///
+///     let mut clear = rugl.clear();
+///     clear.depth = Some(1.0);
+///
+///     backend.clear_calls();
+///     clear.execute();
+///
+///     let calls = backend.calls();
+///     assert!(calls.contains(&"ClearDepth(1.0)".to_string()));
+///     assert_eq!(calls.last().unwrap(), "Clear(256)");
+///
+pub struct RecordingBackend {
+    calls: RefCell<Vec<String>>,
+    next_name: Cell<GLuint>,
+    integers: RefCell<HashMap<GLenum, GLint>>,
+    floats: RefCell<HashMap<GLenum, Vec<GLfloat>>>,
+    shaders: RefCell<HashMap<GLuint, (GLenum, String)>>,
+    program_shaders: RefCell<HashMap<GLuint, Vec<GLuint>>>
+}
+
+impl Default for RecordingBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RecordingBackend {
+    pub fn new() -> RecordingBackend {
+        let mut integers = HashMap::new();
+        integers.insert(gl::MAJOR_VERSION, 3);
+        integers.insert(gl::MINOR_VERSION, 3);
+
+        let mut floats = HashMap::new();
+        floats.insert(gl::ALIASED_LINE_WIDTH_RANGE, vec![1.0, 8.0]);
+        floats.insert(gl::POINT_SIZE_RANGE, vec![1.0, 64.0]);
+
+        RecordingBackend {
+            calls: RefCell::new(Vec::new()),
+            next_name: Cell::new(1),
+            integers: RefCell::new(integers),
+            floats: RefCell::new(floats),
+            shaders: RefCell::new(HashMap::new()),
+            program_shaders: RefCell::new(HashMap::new())
+        }
+    }
+
+    /// A copy of every call that has been recorded so far.
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.borrow().clone()
+    }
+
+    /// Forget the recorded calls, e.g. after setting up a draw command.
+    pub fn clear_calls(&self) {
+        self.calls.borrow_mut().clear();
+    }
+
+    /// Set the value that `get_integer_v` reports for a parameter.
+    pub fn set_integer(&self, parameter: GLenum, value: GLint) {
+        self.integers.borrow_mut().insert(parameter, value);
+    }
+
+    /// Set the values that `get_float_v` reports for a parameter.
+    pub fn set_floats(&self, parameter: GLenum, values: Vec<GLfloat>) {
+        self.floats.borrow_mut().insert(parameter, values);
+    }
+
+    fn record(&self, call: String) {
+        self.calls.borrow_mut().push(call);
+    }
+
+    fn gen_name(&self) -> GLuint {
+        let name = self.next_name.get();
+        self.next_name.set(name + 1);
+        name
+    }
+
+    /// Look through the program's shader sources for variable declarations with a given
+    /// storage qualifier.
+    fn program_variables(&self, program: GLuint, vertex_qualifiers: &[&str], all_qualifiers: &[&str]) -> Vec<ActiveInfo> {
+        let mut variables: Vec<ActiveInfo> = Vec::new();
+        let shaders = self.shaders.borrow();
+        let program_shaders = self.program_shaders.borrow();
+        let shader_names = match program_shaders.get(&program) {
+            Some(shader_names) => shader_names.clone(),
+            None => Vec::new()
+        };
+
+        for shader in shader_names.iter() {
+            let &(shader_type, ref source) = match shaders.get(shader) {
+                Some(shader) => shader,
+                None => continue
+            };
+            for declaration in parse_declarations(source) {
+                let (qualifier, type_name, names) = declaration;
+                let matches_qualifier = all_qualifiers.contains(&qualifier.as_str()) || (
+                    shader_type == gl::VERTEX_SHADER &&
+                    vertex_qualifiers.contains(&qualifier.as_str())
+                );
+                if !matches_qualifier {
+                    continue;
+                }
+                let data_type = match glsl_type_to_enum(&type_name) {
+                    Some(data_type) => data_type,
+                    None => continue
+                };
+                for (name, data_size) in names {
+                    if variables.iter().any(|variable| variable.name == name) {
+                        continue;
+                    }
+                    variables.push(ActiveInfo {
+                        name,
+                        data_size,
+                        data_type
+                    });
+                }
+            }
+        }
+        variables
+    }
+
+    fn program_attributes(&self, program: GLuint) -> Vec<ActiveInfo> {
+        self.program_variables(program, &["in", "attribute"], &[])
+    }
+
+    fn program_uniforms(&self, program: GLuint) -> Vec<ActiveInfo> {
+        self.program_variables(program, &[], &["uniform"])
+    }
+}
+
+/// A GLSL declaration as `(qualifier, type, [(name, array_size)])`.
+type Declaration = (String, String, Vec<(String, GLint)>);
+
+/// Split GLSL source into declarations. This only understands simple global declarations like
+/// `uniform mat4 projection, view;`.
+fn parse_declarations(source: &str) -> Vec<Declaration> {
+    let mut declarations = Vec::new();
+    let without_comments: Vec<&str> = source.lines()
+        .map(|line| {
+            match line.trim_start().starts_with('#') {
+                true => "",
+                false => line.split("//").next().unwrap_or("")
+            }
+        })
+        .collect();
+    let joined = without_comments.join(" ");
+
+    for statement in joined.split([';', '{', '}']) {
+        let tokens: Vec<&str> = statement.split_whitespace()
+            .filter(|token| {
+                !matches!(*token, "highp" | "mediump" | "lowp" | "flat" | "smooth" | "noperspective")
+            })
+            .collect();
+        if tokens.len() < 3 {
+            continue;
+        }
+        let names: Vec<(String, GLint)> = tokens[2..].join("").split(',').map(|name| {
+            match name.find('[') {
+                Some(bracket) => {
+                    let size = name[bracket + 1..].trim_end_matches(']').parse().unwrap_or(1);
+                    (name[..bracket].to_string(), size)
+                },
+                None => (name.to_string(), 1)
+            }
+        }).collect();
+        declarations.push((tokens[0].to_string(), tokens[1].to_string(), names));
+    }
+    declarations
+}
+
+fn glsl_type_to_enum(type_name: &str) -> Option<GLenum> {
+    match type_name {
+        "float" => Some(gl::FLOAT),
+        "vec2" => Some(gl::FLOAT_VEC2),
+        "vec3" => Some(gl::FLOAT_VEC3),
+        "vec4" => Some(gl::FLOAT_VEC4),
+        "mat2" => Some(gl::FLOAT_MAT2),
+        "mat3" => Some(gl::FLOAT_MAT3),
+        "mat4" => Some(gl::FLOAT_MAT4),
+        "mat2x3" => Some(gl::FLOAT_MAT2x3),
+        "mat2x4" => Some(gl::FLOAT_MAT2x4),
+        "mat3x2" => Some(gl::FLOAT_MAT3x2),
+        "mat3x4" => Some(gl::FLOAT_MAT3x4),
+        "mat4x2" => Some(gl::FLOAT_MAT4x2),
+        "mat4x3" => Some(gl::FLOAT_MAT4x3),
+        "int" => Some(gl::INT),
+        "ivec2" => Some(gl::INT_VEC2),
+        "ivec3" => Some(gl::INT_VEC3),
+        "ivec4" => Some(gl::INT_VEC4),
+        "uint" => Some(gl::UNSIGNED_INT),
+        "uvec2" => Some(gl::UNSIGNED_INT_VEC2),
+        "uvec3" => Some(gl::UNSIGNED_INT_VEC3),
+        "uvec4" => Some(gl::UNSIGNED_INT_VEC4),
+        "double" => Some(gl::DOUBLE),
+        "dvec2" => Some(gl::DOUBLE_VEC2),
+        "dvec3" => Some(gl::DOUBLE_VEC3),
+        "dvec4" => Some(gl::DOUBLE_VEC4),
+        _ => None
+    }
+}
+
+impl GlBackend for RecordingBackend {
+    fn create_shader(&self, shader_type: GLenum) -> GLuint {
+        let shader = self.gen_name();
+        self.shaders.borrow_mut().insert(shader, (shader_type, String::new()));
+        self.record(format!("CreateShader({}) -> {}", shader_type, shader));
+        shader
+    }
+
+    fn shader_source(&self, shader: GLuint, source: &str) {
+        if let Some(entry) = self.shaders.borrow_mut().get_mut(&shader) {
+            entry.1 = source.to_string();
+        }
+        self.record(format!("ShaderSource({})", shader));
+    }
+
+    fn compile_shader(&self, shader: GLuint) {
+        self.record(format!("CompileShader({})", shader));
+    }
+
+    fn get_shader_iv(&self, shader: GLuint, parameter: GLenum) -> GLint {
+        self.record(format!("GetShaderiv({}, {})", shader, parameter));
+        match parameter {
+            gl::COMPILE_STATUS => gl::TRUE as GLint,
+            _ => 0
+        }
+    }
+
+    fn get_shader_info_log(&self, shader: GLuint) -> String {
+        self.record(format!("GetShaderInfoLog({})", shader));
+        String::new()
+    }
+
+    fn create_program(&self) -> GLuint {
+        let program = self.gen_name();
+        self.program_shaders.borrow_mut().insert(program, Vec::new());
+        self.record(format!("CreateProgram() -> {}", program));
+        program
+    }
+
+    fn attach_shader(&self, program: GLuint, shader: GLuint) {
+        if let Some(shaders) = self.program_shaders.borrow_mut().get_mut(&program) {
+            shaders.push(shader);
+        }
+        self.record(format!("AttachShader({}, {})", program, shader));
+    }
+
+    fn link_program(&self, program: GLuint) {
+        self.record(format!("LinkProgram({})", program));
+    }
+
+    fn get_program_iv(&self, program: GLuint, parameter: GLenum) -> GLint {
+        self.record(format!("GetProgramiv({}, {})", program, parameter));
+        match parameter {
+            gl::LINK_STATUS => gl::TRUE as GLint,
+            gl::ACTIVE_ATTRIBUTES => self.program_attributes(program).len() as GLint,
+            gl::ACTIVE_UNIFORMS => self.program_uniforms(program).len() as GLint,
+            _ => 0
+        }
+    }
+
+    fn get_program_info_log(&self, program: GLuint) -> String {
+        self.record(format!("GetProgramInfoLog({})", program));
+        String::new()
+    }
+
+    fn use_program(&self, program: GLuint) {
+        self.record(format!("UseProgram({})", program));
+    }
+
+    fn get_active_attrib(&self, program: GLuint, index: GLuint) -> ActiveInfo {
+        self.record(format!("GetActiveAttrib({}, {})", program, index));
+        self.program_attributes(program).remove(index as usize)
+    }
+
+    fn get_active_uniform(&self, program: GLuint, index: GLuint) -> ActiveInfo {
+        self.record(format!("GetActiveUniform({}, {})", program, index));
+        self.program_uniforms(program).remove(index as usize)
+    }
+
+    fn get_uniform_location(&self, program: GLuint, name: &str) -> GLint {
+        self.record(format!("GetUniformLocation({}, {:?})", program, name));
+        match self.program_uniforms(program).iter().position(|uniform| uniform.name == name) {
+            Some(location) => location as GLint,
+            None => -1
+        }
+    }
+
+    fn uniform_fv(&self, location: GLint, components: GLint, values: &[GLfloat]) {
+        self.record(format!("Uniform{}fv({}, {:?})", components, location, values));
+    }
+
+    fn uniform_iv(&self, location: GLint, components: GLint, values: &[GLint]) {
+        self.record(format!("Uniform{}iv({}, {:?})", components, location, values));
+    }
+
+    fn uniform_uiv(&self, location: GLint, components: GLint, values: &[GLuint]) {
+        self.record(format!("Uniform{}uiv({}, {:?})", components, location, values));
+    }
+
+    fn uniform_matrix_fv(&self, location: GLint, columns: GLint, rows: GLint, values: &[GLfloat]) {
+        let name = match columns == rows {
+            true => format!("UniformMatrix{}fv", columns),
+            false => format!("UniformMatrix{}x{}fv", columns, rows)
+        };
+        self.record(format!("{}({}, {:?})", name, location, values));
+    }
+
+    fn gen_buffer(&self) -> GLuint {
+        let buffer = self.gen_name();
+        self.record(format!("GenBuffers() -> {}", buffer));
+        buffer
+    }
+
+    fn bind_buffer(&self, target: GLenum, buffer: GLuint) {
+        self.record(format!("BindBuffer({}, {})", target, buffer));
+    }
+
+    fn buffer_data(&self, target: GLenum, data: &[u8], usage: GLenum) {
+        self.record(format!("BufferData({}, {}, {})", target, data.len(), usage));
+    }
+
+    fn gen_vertex_array(&self) -> GLuint {
+        let vao = self.gen_name();
+        self.record(format!("GenVertexArrays() -> {}", vao));
+        vao
+    }
+
+    fn bind_vertex_array(&self, vao: GLuint) {
+        self.record(format!("BindVertexArray({})", vao));
+    }
+
+    fn delete_vertex_array(&self, vao: GLuint) {
+        self.record(format!("DeleteVertexArrays({})", vao));
+    }
+
+    fn enable_vertex_attrib_array(&self, index: GLuint) {
+        self.record(format!("EnableVertexAttribArray({})", index));
+    }
+
+    fn vertex_attrib_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        data_type: GLenum,
+        normalized: bool,
+        stride: GLsizei,
+        offset: usize
+    ) {
+        self.record(format!(
+            "VertexAttribPointer({}, {}, {}, {}, {}, {})",
+            index, size, data_type, normalized, stride, offset
+        ));
+    }
+
+    fn vertex_attrib_i_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        data_type: GLenum,
+        stride: GLsizei,
+        offset: usize
+    ) {
+        self.record(format!(
+            "VertexAttribIPointer({}, {}, {}, {}, {})",
+            index, size, data_type, stride, offset
+        ));
+    }
+
+    fn vertex_attrib_l_pointer(
+        &self,
+        index: GLuint,
+        size: GLint,
+        data_type: GLenum,
+        stride: GLsizei,
+        offset: usize
+    ) {
+        self.record(format!(
+            "VertexAttribLPointer({}, {}, {}, {}, {})",
+            index, size, data_type, stride, offset
+        ));
+    }
+
+    fn vertex_attrib_divisor(&self, index: GLuint, divisor: GLuint) {
+        self.record(format!("VertexAttribDivisor({}, {})", index, divisor));
+    }
+
+    fn vertex_attrib_4f(&self, index: GLuint, x: GLfloat, y: GLfloat, z: GLfloat, w: GLfloat) {
+        self.record(format!("VertexAttrib4f({}, {:?}, {:?}, {:?}, {:?})", index, x, y, z, w));
+    }
+
+    fn vertex_attrib_i4i(&self, index: GLuint, x: GLint, y: GLint, z: GLint, w: GLint) {
+        self.record(format!("VertexAttribI4i({}, {}, {}, {}, {})", index, x, y, z, w));
+    }
+
+    fn vertex_attrib_i4ui(&self, index: GLuint, x: GLuint, y: GLuint, z: GLuint, w: GLuint) {
+        self.record(format!("VertexAttribI4ui({}, {}, {}, {}, {})", index, x, y, z, w));
+    }
+
+    fn gen_framebuffer(&self) -> GLuint {
+        let framebuffer = self.gen_name();
+        self.record(format!("GenFramebuffers() -> {}", framebuffer));
+        framebuffer
+    }
+
+    fn bind_framebuffer(&self, target: GLenum, framebuffer: GLuint) {
+        self.record(format!("BindFramebuffer({}, {})", target, framebuffer));
+    }
+
+    fn gen_renderbuffer(&self) -> GLuint {
+        let renderbuffer = self.gen_name();
+        self.record(format!("GenRenderbuffers() -> {}", renderbuffer));
+        renderbuffer
+    }
+
+    fn bind_renderbuffer(&self, renderbuffer: GLuint) {
+        self.record(format!("BindRenderbuffer({})", renderbuffer));
+    }
+
+    fn renderbuffer_storage(&self, internal_format: GLenum, width: GLsizei, height: GLsizei) {
+        self.record(format!("RenderbufferStorage({}, {}, {})", internal_format, width, height));
+    }
+
+    fn framebuffer_renderbuffer(&self, attachment: GLenum, renderbuffer: GLuint) {
+        self.record(format!("FramebufferRenderbuffer({}, {})", attachment, renderbuffer));
+    }
+
+    fn check_framebuffer_status(&self, target: GLenum) -> GLenum {
+        self.record(format!("CheckFramebufferStatus({})", target));
+        gl::FRAMEBUFFER_COMPLETE
+    }
+
+    fn delete_framebuffer(&self, framebuffer: GLuint) {
+        self.record(format!("DeleteFramebuffers({})", framebuffer));
+    }
+
+    fn delete_renderbuffer(&self, renderbuffer: GLuint) {
+        self.record(format!("DeleteRenderbuffers({})", renderbuffer));
+    }
+
+    fn clear_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+        self.record(format!("ClearColor({:?}, {:?}, {:?}, {:?})", red, green, blue, alpha));
+    }
+
+    fn clear_depth(&self, depth: GLdouble) {
+        self.record(format!("ClearDepth({:?})", depth));
+    }
+
+    fn clear_stencil(&self, stencil: GLint) {
+        self.record(format!("ClearStencil({})", stencil));
+    }
+
+    fn clear(&self, mask: GLbitfield) {
+        self.record(format!("Clear({})", mask));
+    }
+
+    fn enable(&self, capability: GLenum) {
+        self.record(format!("Enable({})", capability));
+    }
+
+    fn disable(&self, capability: GLenum) {
+        self.record(format!("Disable({})", capability));
+    }
+
+    fn blend_func_separate(
+        &self,
+        src_rgb: GLenum,
+        dst_rgb: GLenum,
+        src_alpha: GLenum,
+        dst_alpha: GLenum
+    ) {
+        self.record(format!(
+            "BlendFuncSeparate({}, {}, {}, {})",
+            src_rgb, dst_rgb, src_alpha, dst_alpha
+        ));
+    }
+
+    fn blend_equation_separate(&self, mode_rgb: GLenum, mode_alpha: GLenum) {
+        self.record(format!("BlendEquationSeparate({}, {})", mode_rgb, mode_alpha));
+    }
+
+    fn blend_color(&self, red: GLfloat, green: GLfloat, blue: GLfloat, alpha: GLfloat) {
+        self.record(format!("BlendColor({:?}, {:?}, {:?}, {:?})", red, green, blue, alpha));
+    }
+
+    fn depth_func(&self, func: GLenum) {
+        self.record(format!("DepthFunc({})", func));
+    }
+
+    fn depth_mask(&self, mask: bool) {
+        self.record(format!("DepthMask({})", mask));
+    }
+
+    fn depth_range(&self, near: GLdouble, far: GLdouble) {
+        self.record(format!("DepthRange({:?}, {:?})", near, far));
+    }
+
+    fn cull_face(&self, mode: GLenum) {
+        self.record(format!("CullFace({})", mode));
+    }
+
+    fn front_face(&self, mode: GLenum) {
+        self.record(format!("FrontFace({})", mode));
+    }
+
+    fn polygon_offset(&self, factor: GLfloat, units: GLfloat) {
+        self.record(format!("PolygonOffset({:?}, {:?})", factor, units));
+    }
+
+    fn stencil_mask(&self, mask: GLuint) {
+        self.record(format!("StencilMask({})", mask));
+    }
+
+    fn stencil_func(&self, func: GLenum, reference: GLint, mask: GLuint) {
+        self.record(format!("StencilFunc({}, {}, {})", func, reference, mask));
+    }
+
+    fn stencil_op_separate(&self, face: GLenum, fail: GLenum, zfail: GLenum, zpass: GLenum) {
+        self.record(format!("StencilOpSeparate({}, {}, {}, {})", face, fail, zfail, zpass));
+    }
+
+    fn viewport(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+        self.record(format!("Viewport({}, {}, {}, {})", x, y, width, height));
+    }
+
+    fn scissor(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+        self.record(format!("Scissor({}, {}, {}, {})", x, y, width, height));
+    }
+
+    fn color_mask(&self, red: bool, green: bool, blue: bool, alpha: bool) {
+        self.record(format!("ColorMask({}, {}, {}, {})", red, green, blue, alpha));
+    }
+
+    fn line_width(&self, width: GLfloat) {
+        self.record(format!("LineWidth({:?})", width));
+    }
+
+    fn point_size(&self, size: GLfloat) {
+        self.record(format!("PointSize({:?})", size));
+    }
+
+    fn draw_arrays(&self, mode: GLenum, first: GLint, count: GLsizei) {
+        self.record(format!("DrawArrays({}, {}, {})", mode, first, count));
+    }
+
+    fn draw_elements(&self, mode: GLenum, count: GLsizei, data_type: GLenum, offset: usize) {
+        self.record(format!("DrawElements({}, {}, {}, {})", mode, count, data_type, offset));
+    }
+
+    fn draw_arrays_instanced(&self, mode: GLenum, first: GLint, count: GLsizei, instances: GLsizei) {
+        self.record(format!("DrawArraysInstanced({}, {}, {}, {})", mode, first, count, instances));
+    }
+
+    fn draw_elements_instanced(
+        &self,
+        mode: GLenum,
+        count: GLsizei,
+        data_type: GLenum,
+        offset: usize,
+        instances: GLsizei
+    ) {
+        self.record(format!(
+            "DrawElementsInstanced({}, {}, {}, {}, {})",
+            mode, count, data_type, offset, instances
+        ));
+    }
+
+    fn finish(&self) {
+        self.record("Finish()".to_string());
+    }
+
+    fn read_pixels(
+        &self,
+        x: GLint,
+        y: GLint,
+        width: GLsizei,
+        height: GLsizei,
+        format: GLenum,
+        pixel_type: GLenum,
+        data: &mut [u8]
+    ) {
+        self.record(format!(
+            "ReadPixels({}, {}, {}, {}, {}, {})",
+            x, y, width, height, format, pixel_type
+        ));
+        // Nothing is ever drawn, so the pixels are always zeroed.
+        for byte in data.iter_mut() {
+            *byte = 0;
+        }
+    }
+
+    fn get_integer_v(&self, parameter: GLenum) -> GLint {
+        self.record(format!("GetIntegerv({})", parameter));
+        match self.integers.borrow().get(&parameter) {
+            Some(value) => *value,
+            None => 0
+        }
+    }
+
+    fn get_float_v(&self, parameter: GLenum, values: &mut [GLfloat]) {
+        self.record(format!("GetFloatv({})", parameter));
+        if let Some(floats) = self.floats.borrow().get(&parameter) {
+            for (value, float) in values.iter_mut().zip(floats.iter()) {
+                *value = *float;
+            }
+        }
+    }
+
+    fn get_error(&self) -> GLenum {
+        gl::NO_ERROR
+    }
+}
@@ -1,144 +1,1178 @@
 extern crate time;
+use super::backend::{GlBackend, NativeBackend};
+use super::caching_backend::CachingBackend;
 use super::glutin;
 use super::gl::types::*;
 use super::gl;
-use super::draw_builder::DrawBuilder;
+use super::attributes::{VertexBuffer, VertexLayout};
+use super::buffers::BufferableData;
+use super::draw_builder::{DrawBuilder, VertexArrays};
 use super::clear::Clear;
+use super::framebuffer::Framebuffer;
 use super::gl_helpers;
+use super::input::Input;
+use super::clock::{Accumulator, Clock, Timer};
+use super::offline::{RenderSettings, Y4mWriter};
+use super::pixels::{PixelFormat, PixelReader, Pixels};
+use super::scope::{ScopeBuilder, ScopeStack};
+use super::state::Limits;
+use std::cell::Cell;
+use std::error;
+use std::fmt;
+use std::io;
+use std::mem;
+use std::rc::Rc;
 use std::string;
 
 pub struct Environment {
+    /// The time of this tick in seconds, as measured by the `Clock`.
     pub time: f64,
+    /// The seconds that passed since the previous tick.
+    pub delta_time: f64,
     pub tick: u64,
+    /// The time of the most recent fixed update, see `Rugl::fixed_update()`.
+    pub fixed_time: f64,
+    /// How far the current time is between the last fixed update and the next one, from 0
+    /// to 1. Interpolate by this amount to get smooth motion out of a fixed update.
+    pub fixed_alpha: f64,
+    /// The size of the drawing buffer in physical pixels.
     pub viewport_width: u32,
     pub viewport_height: u32,
+    /// The size of the window in logical points, which is the viewport size divided by the
+    /// pixel ratio.
+    pub window_width: f32,
+    pub window_height: f32,
+    /// The HiDPI scale factor of the monitor the window is on.
+    pub pixel_ratio: f32,
+    /// The mouse, keyboard and focus state as of this tick.
+    pub input: Input,
+    /// The window that is being drawn, see `Rugl::create_window()`.
+    pub window: WindowId
 }
 
+impl Environment {
+    pub fn new(viewport_width: u32, viewport_height: u32) -> Environment {
+        Environment {
+            time: 0.0,
+            delta_time: 0.0,
+            tick: 0,
+            fixed_time: 0.0,
+            fixed_alpha: 0.0,
+            viewport_width,
+            viewport_height,
+            window_width: viewport_width as f32,
+            window_height: viewport_height as f32,
+            pixel_ratio: 1.0,
+            input: Input::new(),
+            window: WindowId(0)
+        }
+    }
+
+    fn set_size(&mut self, viewport_width: u32, viewport_height: u32, pixel_ratio: f32) {
+        self.viewport_width = viewport_width;
+        self.viewport_height = viewport_height;
+        self.pixel_ratio = pixel_ratio;
+        self.window_width = viewport_width as f32 / pixel_ratio;
+        self.window_height = viewport_height as f32 / pixel_ratio;
+    }
+}
+
+/// Identifies one of the windows of a `Rugl` instance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WindowId(usize);
+
+/// What the frame loop should do after a frame has been drawn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FrameControl {
+    /// Keep drawing frames.
+    Continue,
+    /// Stop the frame loop, and return from `frame()`.
+    Exit,
+    /// Don't draw the next frame until a window event arrives, e.g. for tools that only need
+    /// to redraw in response to input.
+    Wait
+}
+
+/// Lets the frame closure return either nothing or a `FrameControl`.
+pub trait IntoFrameControl {
+    fn into_frame_control(self) -> FrameControl;
+}
+
+impl IntoFrameControl for () {
+    fn into_frame_control(self) -> FrameControl {
+        FrameControl::Continue
+    }
+}
+
+impl IntoFrameControl for FrameControl {
+    fn into_frame_control(self) -> FrameControl {
+        self
+    }
+}
+
+/// The OpenGL version and profile to request when creating the context.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GlVersion {
+    /// Let the platform pick the version.
+    Latest,
+    /// Desktop OpenGL using the core profile, e.g. `GlVersion::Core(3, 3)`.
+    Core(u8, u8),
+    /// Desktop OpenGL using the compatibility profile.
+    Compatibility(u8, u8),
+    /// OpenGL ES, e.g. `GlVersion::Es(3, 0)`.
+    Es(u8, u8)
+}
+
+impl GlVersion {
+    fn to_gl_request(self) -> glutin::GlRequest {
+        match self {
+            GlVersion::Latest => glutin::GlRequest::Latest,
+            GlVersion::Core(major, minor) |
+            GlVersion::Compatibility(major, minor) => {
+                glutin::GlRequest::Specific(glutin::Api::OpenGl, (major, minor))
+            },
+            GlVersion::Es(major, minor) => {
+                glutin::GlRequest::Specific(glutin::Api::OpenGlEs, (major, minor))
+            }
+        }
+    }
+
+    fn to_gl_profile(self) -> Option<glutin::GlProfile> {
+        match self {
+            GlVersion::Core(_, _) => Some(glutin::GlProfile::Core),
+            GlVersion::Compatibility(_, _) => Some(glutin::GlProfile::Compatibility),
+            _ => None
+        }
+    }
+}
+
+/// The ways that creating a `Rugl` context can fail.
+#[derive(Debug)]
+pub enum RuglError {
+    /// The builder was given settings that can never produce a context.
+    InvalidConfig(String),
+    /// The window or GL context could not be created.
+    Creation(glutin::CreationError),
+    /// The GL context was created, but could not be made current.
+    Context(glutin::ContextError)
+}
+
+impl fmt::Display for RuglError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuglError::InvalidConfig(message) => write!(formatter, "Invalid rugl config: {}", message),
+            RuglError::Creation(error) => write!(formatter, "Unable to create the context: {}", error),
+            RuglError::Context(error) => write!(formatter, "Unable to use the context: {}", error)
+        }
+    }
+}
+
+impl error::Error for RuglError {
+    fn description(&self) -> &str {
+        match *self {
+            RuglError::InvalidConfig(_) => "invalid rugl config",
+            RuglError::Creation(_) => "unable to create the context",
+            RuglError::Context(_) => "unable to make the context current"
+        }
+    }
+}
+
+impl From<glutin::CreationError> for RuglError {
+    fn from(error: glutin::CreationError) -> RuglError {
+        RuglError::Creation(error)
+    }
+}
+
+impl From<glutin::ContextError> for RuglError {
+    fn from(error: glutin::ContextError) -> RuglError {
+        RuglError::Context(error)
+    }
+}
+
+/// Configure how the window and GL context are created. Every setting is optional, and the
+/// defaults match what `rugl::init()` creates.
+///
+///     use rugl::{RuglBuilder, GlVersion};
+///
+///     let builder = RuglBuilder::new()
+///         .title("My visualization")
+///         .dimensions(800, 600)
+///         .gl_version(GlVersion::Core(3, 3))
+///         .multisampling(4)
+///         .depth_bits(24)
+///         .stencil_bits(8);
+///
+///     // let rugl = builder.build().expect("Unable to create a context");
+///
+/// Calling `.headless()` skips the window entirely, and renders into an offscreen framebuffer
+/// of the given `dimensions` using a software GL context. This is what the test suite uses.
+pub struct RuglBuilder {
+    title: String,
+    dimensions: Option<(u32, u32)>,
+    gl_version: GlVersion,
+    multisampling: Option<u16>,
+    depth_bits: Option<u8>,
+    stencil_bits: Option<u8>,
+    srgb: Option<bool>,
+    vsync: bool,
+    decorations: bool,
+    clock: Clock,
+    headless: bool
+}
+
+impl Default for RuglBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RuglBuilder {
+    pub fn new() -> RuglBuilder {
+        RuglBuilder {
+            title: "rugl".to_string(),
+            dimensions: None,
+            gl_version: GlVersion::Latest,
+            multisampling: None,
+            depth_bits: None,
+            stencil_bits: Some(8),
+            srgb: None,
+            vsync: true,
+            decorations: true,
+            clock: Clock::Realtime,
+            headless: false
+        }
+    }
+
+    pub fn title(mut self, title: &str) -> RuglBuilder {
+        self.title = title.to_string();
+        self
+    }
+
+    /// The initial size of the window in pixels.
+    pub fn dimensions(mut self, width: u32, height: u32) -> RuglBuilder {
+        self.dimensions = Some((width, height));
+        self
+    }
+
+    pub fn gl_version(mut self, gl_version: GlVersion) -> RuglBuilder {
+        self.gl_version = gl_version;
+        self
+    }
+
+    /// The number of MSAA samples, which must be a power of two.
+    pub fn multisampling(mut self, samples: u16) -> RuglBuilder {
+        self.multisampling = Some(samples);
+        self
+    }
+
+    pub fn depth_bits(mut self, bits: u8) -> RuglBuilder {
+        self.depth_bits = Some(bits);
+        self
+    }
+
+    /// The size of the stencil buffer, this defaults to 8 bits so that draw commands can use
+    /// the stencil test. That's the same size that glutin asks for when it isn't given one, so
+    /// the default is explicit rather than new.
+    pub fn stencil_bits(mut self, bits: u8) -> RuglBuilder {
+        self.stencil_bits = Some(bits);
+        self
+    }
+
+    pub fn srgb(mut self, srgb: bool) -> RuglBuilder {
+        self.srgb = Some(srgb);
+        self
+    }
+
+    pub fn vsync(mut self, vsync: bool) -> RuglBuilder {
+        self.vsync = vsync;
+        self
+    }
+
+    pub fn decorations(mut self, decorations: bool) -> RuglBuilder {
+        self.decorations = decorations;
+        self
+    }
+
+    /// Where the time in the environment comes from, this defaults to `Clock::Realtime`.
+    pub fn clock(mut self, clock: Clock) -> RuglBuilder {
+        self.clock = clock;
+        self
+    }
+
+    /// Render into an offscreen framebuffer instead of a window. The framebuffer always has
+    /// depth and stencil attachments, so the bit depth and multisampling settings are ignored.
+    pub fn headless(mut self) -> RuglBuilder {
+        self.headless = true;
+        self
+    }
+
+    /// Create the window and GL context, and load the GL function pointers.
+    pub fn build(self) -> Result<Rugl, RuglError> {
+        self.validate()?;
+
+        if self.headless {
+            return self.build_headless();
+        }
+
+        let events_loop = glutin::EventsLoop::new();
+        let window = self.window_builder().build(&events_loop)?;
+
+        unsafe { window.make_current()? };
+
+        // Load the OpenGL function pointers
+        gl::load_with(|ptr| window.get_proc_address(ptr) as *const _);
+        let current_context = Rc::new(Cell::new(0));
+        let backend = Rc::new(CachingBackend::new(Rc::new(NativeBackend), current_context.clone()));
+
+        log_draw!(
+            "OpenGL Version {}.{}",
+            gl_helpers::get_major_version(&*backend),
+            gl_helpers::get_minor_version(&*backend)
+        );
+
+        let environment = window_environment(&window, WindowId(0))?;
+        let limits = Limits::query(&*backend);
+
+        Ok(Rugl {
+            timer: Timer::new(self.clock),
+            backend,
+            window: Some(window),
+            events_loop: Some(events_loop),
+            headless: None,
+            windows: Vec::new(),
+            next_window_id: 1,
+            current_context,
+            vertex_arrays: VertexArrays::default(),
+            limits,
+            scopes: ScopeStack::new(),
+            resize_callback: None,
+            fixed_update: None,
+            waiting: false,
+            environment
+        })
+    }
+
+    fn validate(&self) -> Result<(), RuglError> {
+        match self.multisampling {
+            // Headless contexts don't use multisampling, so any value is fine.
+            Some(samples) if !self.headless && !samples.is_power_of_two() => {
+                return Err(RuglError::InvalidConfig(
+                    format!("multisampling must be a power of two, got {}", samples)
+                ));
+            },
+            _ => {}
+        };
+        match self.dimensions {
+            Some((0, _)) | Some((_, 0)) => {
+                return Err(RuglError::InvalidConfig(
+                    "dimensions must be larger than 0".to_string()
+                ));
+            },
+            _ => {}
+        };
+        Ok(())
+    }
+
+    fn window_builder<'a>(&self) -> glutin::WindowBuilder<'a> {
+        let mut window_builder = glutin::WindowBuilder::new()
+            .with_title(self.title.clone())
+            .with_decorations(self.decorations)
+            .with_gl(self.gl_version.to_gl_request())
+            .with_srgb(self.srgb);
+
+        if let Some((width, height)) = self.dimensions {
+            window_builder = window_builder.with_dimensions(width, height);
+        }
+        if let Some(profile) = self.gl_version.to_gl_profile() {
+            window_builder = window_builder.with_gl_profile(profile);
+        }
+        if let Some(samples) = self.multisampling {
+            window_builder = window_builder.with_multisampling(samples);
+        }
+        if let Some(bits) = self.depth_bits {
+            window_builder = window_builder.with_depth_buffer(bits);
+        }
+        if let Some(bits) = self.stencil_bits {
+            window_builder = window_builder.with_stencil_buffer(bits);
+        }
+        if self.vsync {
+            window_builder = window_builder.with_vsync();
+        }
+        window_builder
+    }
+
+    fn build_headless(self) -> Result<Rugl, RuglError> {
+        let (width, height) = self.dimensions.unwrap_or((1000, 600));
+
+        let mut context_builder = glutin::HeadlessRendererBuilder::new(width, height)
+            .with_gl(self.gl_version.to_gl_request());
+
+        if let Some(profile) = self.gl_version.to_gl_profile() {
+            context_builder = context_builder.with_gl_profile(profile);
+        }
+
+        let context = context_builder.build()?;
+
+        unsafe { context.make_current()? };
+
+        // Load the OpenGL function pointers
+        gl::load_with(|ptr| context.get_proc_address(ptr) as *const _);
+        let current_context = Rc::new(Cell::new(0));
+        let backend = Rc::new(CachingBackend::new(Rc::new(NativeBackend), current_context.clone()));
+
+        log_draw!(
+            "OpenGL Version {}.{}",
+            gl_helpers::get_major_version(&*backend),
+            gl_helpers::get_minor_version(&*backend)
+        );
+
+        // Render into a framebuffer that we own, rather than relying on whatever default
+        // framebuffer the software renderer happens to provide.
+        let framebuffer = Framebuffer::new(backend.clone(), width, height);
+        framebuffer.bind();
+        let limits = Limits::query(&*backend);
+
+        Ok(Rugl {
+            timer: Timer::new(self.clock),
+            backend,
+            window: None,
+            events_loop: None,
+            headless: Some(Headless {
+                framebuffer,
+                context
+            }),
+            windows: Vec::new(),
+            next_window_id: 1,
+            current_context,
+            vertex_arrays: VertexArrays::default(),
+            limits,
+            scopes: ScopeStack::new(),
+            resize_callback: None,
+            fixed_update: None,
+            waiting: false,
+            environment: Environment::new(width, height)
+        })
+    }
+}
+
+/// The offscreen context. The framebuffer is declared first, as fields are dropped in order,
+/// so that it's deleted while its context is still alive and current.
+struct Headless {
+    framebuffer: Framebuffer,
+    // Never read, it only has to stay alive as long as the framebuffer.
+    #[allow(dead_code)]
+    context: glutin::HeadlessContext
+}
+
+/// A window created with `Rugl::create_window()`, which shares its GL resources with the main
+/// window.
+struct SharedWindow {
+    window: glutin::Window,
+    environment: Environment
+}
+
+struct FixedUpdate {
+    accumulator: Accumulator,
+    callback: Box<dyn FnMut(&Environment)>
+}
+
+/// Called with the new `Environment` whenever the window is resized.
+type ResizeCallback = Box<dyn FnMut(&Environment)>;
+
 pub struct Rugl {
-    start_time: f64,
+    timer: Timer,
+    backend: Rc<CachingBackend>,
     window: Option<glutin::Window>,
     events_loop: Option<glutin::EventsLoop>,
+    headless: Option<Headless>,
+    windows: Vec<SharedWindow>,
+    next_window_id: usize,
+    current_context: Rc<Cell<usize>>,
+    vertex_arrays: VertexArrays,
+    limits: Limits,
+    scopes: ScopeStack,
+    resize_callback: Option<ResizeCallback>,
+    fixed_update: Option<FixedUpdate>,
+    waiting: bool,
     environment: Environment
 }
 
+/// Create a windowed `Rugl` instance with the default settings. This panics if the context
+/// can't be created, use a `RuglBuilder` to handle the error instead.
 pub fn init() -> Rugl {
-    let events_loop = glutin::EventsLoop::new();
-    let window = glutin::WindowBuilder::new()
-        .with_vsync()
-        .with_title("rugl")
-        .with_decorations(true)
-        .build(&events_loop)
-        .unwrap();
-
-    let _ = unsafe { window.make_current() };
-
-    // Load the OpenGL function pointers
-    gl::load_with(|ptr| window.get_proc_address(ptr) as *const _);
-
-    log_draw!(
-        "OpenGL Version {}.{}",
-        gl_helpers::get_major_version(),
-        gl_helpers::get_minor_version()
-    );
-
-    let (viewport_width, viewport_height) = window.get_inner_size_pixels().unwrap();
-
-    Rugl {
-        start_time: time::precise_time_s(),
-        window: Some(window),
-        events_loop: Some(events_loop),
-        environment: Environment {
-            time: 0.0,
-            tick: 0,
-            viewport_width: viewport_width,
-            viewport_height: viewport_height
-        }
-    }
+    RuglBuilder::new().build().unwrap()
 }
 
+/// Create an offscreen `Rugl` instance backed by a software GL context. This panics if the
+/// context can't be created.
 pub fn init_headless() -> Rugl {
+    RuglBuilder::new().headless().build().unwrap()
+}
+
+/// Create a `Rugl` instance without a window or context that sends every GL call to the given
+/// backend, e.g. a `RecordingBackend` in tests. `frame()` runs the draw closure once.
+pub fn init_with_backend(backend: Rc<dyn GlBackend>) -> Rugl {
+    let current_context = Rc::new(Cell::new(0));
+    let backend = Rc::new(CachingBackend::new(backend, current_context.clone()));
+    let limits = Limits::query(&*backend);
     Rugl {
-        start_time: time::precise_time_s(),
+        timer: Timer::new(Clock::Realtime),
+        backend,
         window: None,
         events_loop: None,
-        environment: Environment {
-            time: 0.0,
-            tick: 0,
-            viewport_width: 1000,
-            viewport_height: 600
-        }
+        headless: None,
+        windows: Vec::new(),
+        next_window_id: 1,
+        current_context,
+        vertex_arrays: VertexArrays::default(),
+        limits,
+        scopes: ScopeStack::new(),
+        resize_callback: None,
+        fixed_update: None,
+        waiting: false,
+        environment: Environment::new(1000, 600)
     }
 }
 
 impl Rugl {
     pub fn draw(&self) -> DrawBuilder {
-        // Eventually some shared state will be injected here.
-        DrawBuilder::new()
+        // Eventually more shared state will be injected here.
+        DrawBuilder::new(
+            self.backend.clone(),
+            self.current_context.clone(),
+            self.vertex_arrays.clone(),
+            self.limits,
+            self.scopes.clone()
+        )
+    }
+
+    /// Build a draw command that is called with props of type `P`, which the uniforms,
+    /// attributes and counts can be computed from. See `DrawCommand` for an example.
+    pub fn draw_with_props<P: 'static>(&self) -> DrawBuilder<P> {
+        DrawBuilder::new(
+            self.backend.clone(),
+            self.current_context.clone(),
+            self.vertex_arrays.clone(),
+            self.limits,
+            self.scopes.clone()
+        )
+    }
+
+    /// Upload vertex data into a buffer, so that it can be picked by `attribute_prop()`.
+    pub fn buffer(&self, data: &dyn BufferableData) -> GLuint {
+        data.to_buffer(&*self.backend)
+    }
+
+    /// Upload vertex structs into a single interleaved buffer, see `VertexLayout`.
+    pub fn vertex_buffer<T: VertexLayout>(&self, vertices: &[T]) -> VertexBuffer {
+        VertexBuffer::new(&*self.backend, vertices)
+    }
+
+    /// Build a scope, which passes uniforms, a framebuffer and render state down to the draw
+    /// commands that run inside of it. See `Scope` for an example.
+    pub fn scope(&self) -> ScopeBuilder {
+        ScopeBuilder::new(self.backend.clone(), self.scopes.clone())
     }
 
     pub fn clear(&self) -> Clear {
-        // Eventually some shared state will be injected here.
-        Clear::new()
+        // Eventually more shared state will be injected here.
+        Clear::new(self.backend.clone())
+    }
+
+    /// The ranges of settings that the GL supports.
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    /// Forget the GL state that has been cached to skip redundant calls. This must be called
+    /// after changing the GL state directly, rather than through rugl's commands.
+    pub fn reset_state_cache(&self) {
+        self.backend.reset();
+    }
+
+    /// The window created along with `Rugl`.
+    pub fn main_window(&self) -> WindowId {
+        WindowId(0)
+    }
+
+    /// Open another window that shares buffers and programs with the main window, so anything
+    /// made with `rugl.draw()` can be drawn into any window. The frame closure is run once for
+    /// every window, check `environment.window` to decide what to draw. Closing the extra
+    /// window only removes it from the frame loop, while closing the main window still ends
+    /// the loop.
+    ///
+    /// Framebuffers aren't shared between contexts, so a `Framebuffer` can only be used while
+    /// drawing the main window.
+    ///
+    ///     use rugl::{Rugl, RuglBuilder};
+    ///
+    ///     fn run(rugl: &mut Rugl) {
+    ///         let inspector = rugl.create_window(RuglBuilder::new().title("Inspector")).unwrap();
+    ///
+    ///         rugl.frame(|env| {
+    ///             if env.window == inspector {
+    ///                 // Draw the inspector.
+    ///             } else {
+    ///                 // Draw the scene.
+    ///             }
+    ///         });
+    ///     }
+    pub fn create_window(&mut self, builder: RuglBuilder) -> Result<WindowId, RuglError> {
+        builder.validate()?;
+
+        let (main_window, events_loop) = match (&self.window, &self.events_loop) {
+            (Some(window), Some(events_loop)) if !builder.headless => (window, events_loop),
+            _ => return Err(RuglError::InvalidConfig(
+                "only a windowed rugl can create more windows".to_string()
+            ))
+        };
+
+        let window = builder.window_builder()
+            .with_shared_lists(main_window)
+            .build(events_loop)?;
+
+        let id = WindowId(self.next_window_id);
+        let mut environment = window_environment(&window, id)?;
+        environment.time = self.environment.time;
+        environment.tick = self.environment.tick;
+
+        // Make sure that anything created from here on goes to the main context.
+        unsafe { main_window.make_current()? };
+
+        self.next_window_id += 1;
+        self.windows.push(SharedWindow {
+            window,
+            environment
+        });
+        Ok(id)
     }
 
-    pub fn frame<F>(&mut self, draw: F) where
-        F: Fn(&Environment)
+    /// Run a callback whenever a window is resized, after its environment has been updated
+    /// with the new size. This is the place to reallocate anything that depends on the size
+    /// of the viewport.
+    pub fn on_resize<F>(&mut self, callback: F) where
+        F: FnMut(&Environment) + 'static
     {
-        let start_time = self.start_time;
-        #[allow(unused_variables)]
-        let mut previous_time = time::precise_time_s();
+        self.resize_callback = Some(Box::new(callback));
+    }
 
-        let ref mut environment = self.environment;
+    /// Run a callback every `step` seconds of clock time, before the frame is drawn. This
+    /// can run several times, or not at all, in a single frame depending on how long the frame
+    /// took. It's the place for simulations that need a constant time step to be stable, and
+    /// `environment.fixed_alpha` can be used to interpolate between updates when drawing.
+    pub fn fixed_update<F>(&mut self, step: f64, callback: F) where
+        F: FnMut(&Environment) + 'static
+    {
+        assert!(step > 0.0, "The fixed update step must be larger than 0");
+        self.fixed_update = Some(FixedUpdate {
+            accumulator: Accumulator::new(step),
+            callback: Box::new(callback)
+        });
+    }
 
-        match self {
-            // Only run the following code if this is a real instance. This code won't run
-            // if it's headless in the test suite.
-            &mut Rugl {
+    /// Change where the time in the environment comes from.
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.timer.set_clock(clock);
+    }
+
+    /// Move the clock forward, this is the only way time passes with `Clock::Manual`. The new
+    /// time is picked up on the next frame.
+    pub fn advance_clock(&mut self, seconds: f64) {
+        self.timer.advance(seconds);
+    }
+
+    /// Run the frame loop until the window is closed, or the draw closure returns
+    /// `FrameControl::Exit`. The closure can return nothing, which is the same as returning
+    /// `FrameControl::Continue`. Without a window there is nothing to drive the loop, so only
+    /// a single frame is drawn, use `step()` to draw more.
+    pub fn frame<F, R>(&mut self, mut draw: F) where
+        F: FnMut(&Environment) -> R,
+        R: IntoFrameControl
+    {
+        loop {
+            let control = self.step(|environment| draw(environment));
+
+            if cfg!(feature = "draw_once") || control == FrameControl::Exit ||
+                self.window.is_none()
+            {
+                break;
+            }
+        }
+    }
+
+    /// Read back a rectangle of what has been drawn so far, with `x` and `y` measured in
+    /// pixels from the top left of the viewport. This is meant for headless contexts, where
+    /// the frame stays in the offscreen framebuffer after `frame()` returns. A window's back
+    /// buffer is undefined once it has been swapped, so use a `pixel_reader()` inside of the
+    /// frame closure instead.
+    ///
+    ///     #[macro_use]
+    ///     extern crate rugl;
+    ///     use rugl::pixels::PixelFormat;
+    ///
+    ///     fn render_red() {
+    ///         let mut rugl = rugl::init_headless();
+    ///         let clear = rugl!(rugl.clear, { color => [1.0, 0.0, 0.0, 1.0] });
+    ///
+    ///         rugl.frame(|_| clear());
+    ///         let pixels = rugl.read_pixels(0, 0, 10, 10, PixelFormat::Rgba8);
+    ///         assert_eq!(pixels.get(5, 5), [1.0, 0.0, 0.0, 1.0]);
+    ///     }
+    ///
+    ///     // This needs a GL driver for the headless context.
+    ///     let _ = render_red;
+    pub fn read_pixels(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        format: PixelFormat
+    ) -> Pixels {
+        Pixels::read(
+            &*self.backend,
+            x,
+            y,
+            width,
+            height,
+            self.environment.viewport_width,
+            self.environment.viewport_height,
+            format
+        )
+    }
+
+    /// Create a handle that reads pixels from inside of the frame closure, e.g. for GPU
+    /// picking or comparing a window against a golden image. See `PixelReader`.
+    pub fn pixel_reader(&self) -> PixelReader {
+        PixelReader::new(self.backend.clone())
+    }
+
+    /// Draw a fixed number of frames offscreen, and write each one to disk as described by the
+    /// `RenderSettings`. The clock advances exactly `1 / fps` seconds per frame starting from
+    /// 0, so the output is the same no matter how long each frame takes. The window, if there
+    /// is one, isn't touched. The draw closure can return `FrameControl::Exit` to stop early.
+    pub fn render<F, R>(&mut self, settings: &RenderSettings, mut draw: F) -> io::Result<()> where
+        F: FnMut(&Environment) -> R,
+        R: IntoFrameControl
+    {
+        assert!(settings.fps > 0, "The frames per second must be larger than 0");
+        settings.create_directories()?;
+
+        let mut y4m = match settings.y4m {
+            Some(ref path) => {
+                Some(Y4mWriter::create(path, settings.width, settings.height, settings.fps)?)
+            },
+            None => None
+        };
+        let framebuffer = Framebuffer::new(self.backend.clone(), settings.width, settings.height);
+
+        // Swap in a fresh clock and environment, and put the real ones back once done.
+        let timer = mem::replace(
+            &mut self.timer,
+            Timer::new(Clock::FixedStep(1.0 / settings.fps as f64))
+        );
+        let environment = mem::replace(
+            &mut self.environment,
+            Environment::new(settings.width, settings.height)
+        );
+
+        let mut result = Ok(());
+        for frame in 0..settings.frames {
+            framebuffer.bind();
+            update_time(&mut self.environment, &mut self.timer, &mut self.fixed_update);
+            reset_frame_state(&*self.backend, &self.environment);
+            let control = draw(&self.environment).into_frame_control();
+
+            let pixels = Pixels::read(
+                &*self.backend,
+                0,
+                0,
+                settings.width,
+                settings.height,
+                settings.width,
+                settings.height,
+                PixelFormat::Rgba8
+            );
+            result = pixels.save_png(settings.frame_path(frame));
+            if let (true, &mut Some(ref mut y4m)) = (result.is_ok(), &mut y4m) {
+                result = y4m.write_frame(&pixels.to_rgba8());
+            }
+            if result.is_err() || control == FrameControl::Exit {
+                break;
+            }
+        }
+
+        self.timer = timer;
+        self.environment = environment;
+        match self.headless {
+            Some(ref headless) => headless.framebuffer.bind(),
+            None => gl_helpers::bind_framebuffer(&*self.backend, 0)
+        };
+        // Delete the framebuffer now, while the context that created it is still current.
+        drop(framebuffer);
+
+        result?;
+        match y4m {
+            Some(y4m) => y4m.finish(),
+            None => Ok(())
+        }
+    }
+
+    /// Process the pending window events and draw a single frame. This is for applications
+    /// that want to own their loop rather than handing it over to `frame()`. If the previous
+    /// step returned `FrameControl::Wait`, this blocks until the next window event arrives.
+    /// Closing the window returns `FrameControl::Exit` without drawing.
+    pub fn step<F, R>(&mut self, mut draw: F) -> FrameControl where
+        F: FnMut(&Environment) -> R,
+        R: IntoFrameControl
+    {
+        let wait_for_event = self.waiting;
+
+        let control = match *self {
+            Rugl {
                 window: Some(ref window),
                 events_loop: Some(ref events_loop),
+                ref backend,
+                ref mut windows,
+                ref current_context,
+                ref vertex_arrays,
+                ref mut resize_callback,
+                ref mut timer,
+                ref mut fixed_update,
+                ref mut environment,
                 ..
             } => {
-                let mut run_loop = true;
-                while run_loop {
-                    #[cfg(feature = "draw_once")] {
+                let mut events = Vec::new();
+                if wait_for_event {
+                    events_loop.run_forever(|event| {
+                        events.push(event);
                         events_loop.interrupt();
-                        run_loop = false;
+                    });
+                }
+                events_loop.poll_events(|event| events.push(event));
+
+                let mut closed = false;
+                let mut resized = None;
+                let mut shared_closed = vec![false; windows.len()];
+                let mut shared_resized = vec![None; windows.len()];
+
+                environment.input.start_tick();
+                for shared in windows.iter_mut() {
+                    shared.environment.input.start_tick();
+                }
+
+                // Route every event to the window it belongs to.
+                for event in events {
+                    let glutin::Event::WindowEvent { window_id, event } = event;
+                    if window_id == window.id() {
+                        closed |= handle_window_event(event, environment, &mut resized);
+                    } else if let Some(index) = windows.iter().position(|shared| {
+                        shared.window.id() == window_id
+                    }) {
+                        shared_closed[index] |= handle_window_event(
+                            event,
+                            &mut windows[index].environment,
+                            &mut shared_resized[index]
+                        );
                     }
-                    events_loop.poll_events(|event| {
-                        match event {
-                            glutin::Event::WindowEvent { event: glutin::WindowEvent::Closed, .. } => {
-                                events_loop.interrupt();
-                                run_loop = false;
-                            },
-                            _ => {}
+                }
+
+                if closed {
+                    FrameControl::Exit
+                } else {
+                    // The vertex arrays of a closing window's context aren't shared, so
+                    // they're deleted while that context is still current.
+                    for index in (0..windows.len()).rev() {
+                        if shared_closed[index] {
+                            let _ = unsafe { windows[index].window.make_current() };
+                            current_context.set(windows[index].environment.window.0);
+                            release_context(backend, vertex_arrays, current_context.get());
+                            windows.remove(index);
+                            shared_resized.remove(index);
                         }
-                    });
+                    }
+                    if shared_closed.contains(&true) {
+                        let _ = unsafe { window.make_current() };
+                        current_context.set(0);
+                    }
 
-                    let now = time::precise_time_s();
-                    log_draw!("update loop time:  {}ms", (now - previous_time) * 1000.0);
+                    update_time(environment, timer, fixed_update);
+                    log_draw!("update loop time:  {}ms", environment.delta_time * 1000.0);
                     log_draw!("-------------------------------------------------");
-                    previous_time = now;
-                    environment.time = now - start_time;
-                    environment.tick += 1;
-
-                    unsafe {
-                        gl::Enable(gl::DEPTH_TEST);
-                        log_draw!("gl::Enable(gl::DEPTH_TEST)");
-                        gl::BlendFuncSeparate(gl::ONE, gl::ZERO, gl::ONE, gl::ZERO);
-                        log_draw!("gl::BlendFuncSeparate(gl::ONE, gl::ZERO, gl::ONE, gl::ZERO);");
-                    }
-                    draw(&environment);
 
-                    log_draw!("draw time:         {}ms", (time::precise_time_s() - now) * 1000.0);
-                    let _ = window.swap_buffers();
+                    let mut control = draw_window(
+                        window,
+                        environment,
+                        resized,
+                        &**backend,
+                        resize_callback,
+                        &mut draw
+                    );
+
+                    for (shared, resized) in windows.iter_mut().zip(shared_resized) {
+                        let _ = unsafe { shared.window.make_current() };
+                        current_context.set(shared.environment.window.0);
+
+                        shared.environment.time = environment.time;
+                        shared.environment.delta_time = environment.delta_time;
+                        shared.environment.tick = environment.tick;
+                        shared.environment.fixed_time = environment.fixed_time;
+                        shared.environment.fixed_alpha = environment.fixed_alpha;
 
-                    log_draw!("swap buffers time: {}ms", (time::precise_time_s() - now) * 1000.0);
+                        let shared_control = draw_window(
+                            &shared.window,
+                            &mut shared.environment,
+                            resized,
+                            &**backend,
+                            resize_callback,
+                            &mut draw
+                        );
+                        // Any window can end the loop, but only wait if every window is.
+                        control = match (control, shared_control) {
+                            (FrameControl::Exit, _) | (_, FrameControl::Exit) => FrameControl::Exit,
+                            (FrameControl::Wait, FrameControl::Wait) => FrameControl::Wait,
+                            _ => FrameControl::Continue
+                        };
+                    }
+                    if !windows.is_empty() {
+                        let _ = unsafe { window.make_current() };
+                        current_context.set(0);
+                    }
+                    control
                 }
             }
-            _ => {
-                // Do not do anything for now, but eventually it would be nice to track
-                // the GL state changes over time.
+            Rugl {
+                ref headless,
+                ref backend,
+                ref mut timer,
+                ref mut fixed_update,
+                ref mut environment,
+                ..
+            } => {
+                // Render into the offscreen framebuffer (if there is one) and wait for the GL
+                // to finish the frame.
+                let now = time::precise_time_s();
+                update_time(environment, timer, fixed_update);
+
+                if let Some(headless) = headless {
+                    headless.framebuffer.bind();
+                }
+                reset_frame_state(&**backend, environment);
+                let control = draw(environment).into_frame_control();
+                gl_helpers::finish(&**backend);
+
+                log_draw!("draw time:         {}ms", (time::precise_time_s() - now) * 1000.0);
+                control
             }
         };
+
+        self.waiting = control == FrameControl::Wait;
+        control
+    }
+}
+
+/// Create the environment for a newly created window.
+fn window_environment(window: &glutin::Window, id: WindowId) -> Result<Environment, RuglError> {
+    let (viewport_width, viewport_height) = match window.get_inner_size_pixels() {
+        Some(size) => size,
+        None => return Err(RuglError::Creation(glutin::CreationError::OsError(
+            "The window was closed while it was being created".to_string()
+        )))
+    };
+
+    let mut environment = Environment::new(viewport_width, viewport_height);
+    environment.set_size(viewport_width, viewport_height, window.hidpi_factor());
+    environment.window = id;
+    Ok(environment)
+}
+
+/// Update a window's environment from one of its events, and return true if the window was
+/// closed. Only the last resize is kept, as there can be many of them while the window is
+/// being dragged.
+fn handle_window_event(
+    event: glutin::WindowEvent,
+    environment: &mut Environment,
+    resized: &mut Option<(u32, u32)>
+) -> bool {
+    match event {
+        glutin::WindowEvent::Closed => return true,
+        glutin::WindowEvent::Resized(width, height) => *resized = Some((width, height)),
+        event => {
+            environment.input.handle_event(
+                &event,
+                environment.viewport_width,
+                environment.viewport_height
+            );
+        }
+    };
+    false
+}
+
+/// Draw a frame into a window, the window's context must already be current.
+/// Delete the vertex arrays that draw commands created in a context, which has to be current.
+fn release_context(backend: &CachingBackend, vertex_arrays: &VertexArrays, context: usize) {
+    let released = vertex_arrays.borrow_mut().remove(&context).unwrap_or_default();
+    for vao in released {
+        gl_helpers::delete_vao(backend, vao);
+    }
+    backend.forget_context(context);
+}
+
+fn draw_window<F, R>(
+    window: &glutin::Window,
+    environment: &mut Environment,
+    resized: Option<(u32, u32)>,
+    backend: &dyn GlBackend,
+    resize_callback: &mut Option<ResizeCallback>,
+    draw: &mut F
+) -> FrameControl where
+    F: FnMut(&Environment) -> R,
+    R: IntoFrameControl
+{
+    if let Some((width, height)) = resized {
+        // The context follows the size of its window, glutin resizes the surface itself.
+        environment.set_size(width, height, window.hidpi_factor());
+        if let &mut Some(ref mut callback) = resize_callback {
+            callback(environment);
+        }
+    }
+
+    let now = time::precise_time_s();
+    reset_frame_state(backend, environment);
+    let control = draw(environment).into_frame_control();
+
+    log_draw!("draw time:         {}ms", (time::precise_time_s() - now) * 1000.0);
+    let _ = window.swap_buffers();
+
+    log_draw!("swap buffers time: {}ms", (time::precise_time_s() - now) * 1000.0);
+    control
+}
+
+/// Move the environment on to the next tick, and run any fixed updates that are due.
+fn update_time(
+    environment: &mut Environment,
+    timer: &mut Timer,
+    fixed_update: &mut Option<FixedUpdate>
+) {
+    let time = timer.tick();
+    environment.delta_time = time - environment.time;
+    environment.time = time;
+    environment.tick += 1;
+
+    if let &mut Some(ref mut fixed_update) = fixed_update {
+        let steps = fixed_update.accumulator.add(environment.delta_time);
+        for _ in 0..steps {
+            environment.fixed_time += fixed_update.accumulator.step;
+            (fixed_update.callback)(environment);
+        }
+        environment.fixed_alpha = fixed_update.accumulator.alpha();
+    }
+}
+
+fn reset_frame_state(backend: &dyn GlBackend, environment: &Environment) {
+    gl_helpers::viewport(
+        backend,
+        0,
+        0,
+        environment.viewport_width as GLsizei,
+        environment.viewport_height as GLsizei
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::recording_backend::RecordingBackend;
+
+    #[test]
+    fn releasing_a_context_deletes_its_vertex_arrays() {
+        let backend = Rc::new(RecordingBackend::new());
+        let rugl = init_with_backend(backend.clone());
+        let draw = rugl.draw()
+            .vert("in vec2 position; void main() { gl_Position = vec4(position, 0, 1); }")
+            .frag("out vec4 color; void main() { color = vec4(1); }")
+            .attribute("position", &vec![[0.0f32, 0.5], [0.5, -0.5], [-0.5, -0.5]])
+            .count(3)
+            .finalize();
+
+        // Draw into a second context, then close it.
+        rugl.current_context.set(1);
+        backend.clear_calls();
+        draw(&rugl.environment);
+        let window_vao = rugl.vertex_arrays.borrow()[&1][0];
+        backend.clear_calls();
+        release_context(&rugl.backend, &rugl.vertex_arrays, 1);
+
+        assert_eq!(backend.calls(), vec![format!("DeleteVertexArrays({})", window_vao)]);
+        assert!(!rugl.vertex_arrays.borrow().contains_key(&1));
+        assert_eq!(rugl.vertex_arrays.borrow()[&0].len(), 1);
+
+        // The cached state of the closed context is gone, so a new context with the same id
+        // sets its program again.
+        backend.clear_calls();
+        draw(&rugl.environment);
+        assert!(backend.calls().iter().any(|call| call.starts_with("UseProgram")));
+    }
+
+    #[test]
+    fn contexts_have_a_stencil_buffer_by_default() {
+        assert_eq!(RuglBuilder::new().stencil_bits, Some(8));
+        assert_eq!(RuglBuilder::new().stencil_bits(0).stencil_bits, Some(0));
+    }
+
+    #[test]
+    fn multisampling_must_be_a_power_of_two() {
+        assert!(RuglBuilder::new().multisampling(4).validate().is_ok());
+        assert!(matches!(
+            RuglBuilder::new().multisampling(3).validate(),
+            Err(RuglError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn dimensions_must_be_larger_than_zero() {
+        assert!(RuglBuilder::new().dimensions(1, 1).validate().is_ok());
+        assert!(matches!(
+            RuglBuilder::new().dimensions(0, 600).validate(),
+            Err(RuglError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            RuglBuilder::new().dimensions(800, 0).validate(),
+            Err(RuglError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn headless_contexts_ignore_the_buffer_settings() {
+        let builder = RuglBuilder::new()
+            .headless()
+            .multisampling(3)
+            .depth_bits(17)
+            .stencil_bits(3);
+
+        assert!(builder.validate().is_ok());
+        assert!(matches!(
+            builder.dimensions(0, 0).validate(),
+            Err(RuglError::InvalidConfig(_))
+        ));
+    }
+
+    // Headless contexts need OSMesa, run this with `cargo test -- --ignored` where it exists.
+    #[test]
+    #[ignore]
+    fn headless_contexts_render_into_their_framebuffer() {
+        let mut rugl = RuglBuilder::new()
+            .dimensions(16, 16)
+            .headless()
+            .build()
+            .expect("Unable to create a headless context");
+
+        let mut clear = rugl.clear();
+        clear.color = Some([0.0, 1.0, 0.0, 1.0]);
+        rugl.frame(|_| clear.execute());
+
+        let pixels = rugl.read_pixels(0, 0, 16, 16, PixelFormat::Rgba8);
+        assert_eq!(pixels.get(0, 0), [0.0, 1.0, 0.0, 1.0]);
+        assert_eq!(pixels.get(15, 15), [0.0, 1.0, 0.0, 1.0]);
     }
 }
@@ -0,0 +1,338 @@
+use super::backend::GlBackend;
+use super::draw_builder::UniformFn;
+use super::framebuffer::Framebuffer;
+use super::gl;
+use super::gl::types::*;
+use super::rugl::Environment;
+use super::state::{Blend, Cull, Depth, Dynamic, FrontFace, PolygonOffset, Rect, Stencil};
+use super::uniforms::UniformValue;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// A uniform setter that a scope shares with every command that runs inside of it.
+pub type SharedUniformFn = Rc<dyn Fn(&Environment) -> Box<dyn UniformValue>>;
+
+/// The settings that a scope passes down to the draw commands that run inside of it. Every
+/// setting is optional, and settings that a scope leaves out come from the scope around it.
+pub struct ScopeState {
+    pub uniforms: HashMap<String, SharedUniformFn>,
+    pub viewport: Option<Rc<Dynamic<Rect>>>,
+    pub scissor: Option<Rc<Dynamic<Rect>>>,
+    pub depth: Option<Depth>,
+    pub blend: Option<Blend>,
+    pub cull: Option<Cull>,
+    pub front_face: Option<FrontFace>,
+    pub polygon_offset: Option<PolygonOffset>,
+    pub stencil: Option<Stencil>,
+    pub color_mask: Option<[bool; 4]>
+}
+
+/// The scopes that are currently running, from the outermost to the innermost. `Rugl` shares
+/// one stack with all of its draw commands.
+#[derive(Clone)]
+pub struct ScopeStack {
+    scopes: Rc<RefCell<Vec<Rc<ScopeState>>>>
+}
+
+impl Default for ScopeStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScopeStack {
+    pub fn new() -> ScopeStack {
+        ScopeStack {
+            scopes: Rc::new(RefCell::new(Vec::new()))
+        }
+    }
+
+    /// Get a setting from the innermost scope that has it.
+    pub fn find<T, F>(&self, setting: F) -> Option<T> where
+        F: Fn(&ScopeState) -> Option<T>
+    {
+        self.scopes.borrow().iter().rev().filter_map(|scope| setting(scope)).next()
+    }
+
+    pub fn uniform(&self, name: &str) -> Option<SharedUniformFn> {
+        self.find(|scope| scope.uniforms.get(name).cloned())
+    }
+
+    fn push(&self, scope: Rc<ScopeState>) {
+        self.scopes.borrow_mut().push(scope);
+    }
+
+    fn pop(&self) {
+        self.scopes.borrow_mut().pop();
+    }
+}
+
+/// Builds a `Scope`, which is created with `Rugl::scope()`.
+pub struct ScopeBuilder {
+    pub state: ScopeState,
+    framebuffer: Option<Rc<Framebuffer>>,
+    stack: ScopeStack,
+    backend: Rc<dyn GlBackend>
+}
+
+impl ScopeBuilder {
+    pub fn new(backend: Rc<dyn GlBackend>, stack: ScopeStack) -> ScopeBuilder {
+        ScopeBuilder {
+            state: ScopeState {
+                uniforms: HashMap::new(),
+                viewport: None,
+                scissor: None,
+                depth: None,
+                blend: None,
+                cull: None,
+                front_face: None,
+                polygon_offset: None,
+                stencil: None,
+                color_mask: None
+            },
+            framebuffer: None,
+            stack,
+            backend
+        }
+    }
+
+    /// Set a uniform for every command inside of the scope that doesn't set it itself.
+    pub fn uniform(mut self, name: &str, setter: UniformFn) -> ScopeBuilder {
+        self.state.uniforms.insert(name.to_string(), Rc::from(setter));
+        self
+    }
+
+    /// Draw into a framebuffer instead of the window. Unless the scope is given a viewport,
+    /// commands without their own viewport cover the whole framebuffer.
+    pub fn framebuffer(mut self, framebuffer: Rc<Framebuffer>) -> ScopeBuilder {
+        self.framebuffer = Some(framebuffer);
+        self
+    }
+
+    pub fn viewport<D: Into<Dynamic<Rect>>>(mut self, viewport: D) -> ScopeBuilder {
+        self.state.viewport = Some(Rc::new(viewport.into()));
+        self
+    }
+
+    pub fn scissor<D: Into<Dynamic<Rect>>>(mut self, scissor: D) -> ScopeBuilder {
+        self.state.scissor = Some(Rc::new(scissor.into()));
+        self
+    }
+
+    pub fn depth(mut self, depth: Depth) -> ScopeBuilder {
+        self.state.depth = Some(depth);
+        self
+    }
+
+    pub fn blend(mut self, blend: Blend) -> ScopeBuilder {
+        self.state.blend = Some(blend);
+        self
+    }
+
+    pub fn cull(mut self, cull: Cull) -> ScopeBuilder {
+        self.state.cull = Some(cull);
+        self
+    }
+
+    pub fn front_face(mut self, front_face: FrontFace) -> ScopeBuilder {
+        self.state.front_face = Some(front_face);
+        self
+    }
+
+    pub fn polygon_offset(mut self, polygon_offset: PolygonOffset) -> ScopeBuilder {
+        self.state.polygon_offset = Some(polygon_offset);
+        self
+    }
+
+    pub fn stencil(mut self, stencil: Stencil) -> ScopeBuilder {
+        self.state.stencil = Some(stencil);
+        self
+    }
+
+    pub fn color_mask(mut self, color_mask: [bool; 4]) -> ScopeBuilder {
+        self.state.color_mask = Some(color_mask);
+        self
+    }
+
+    pub fn finalize(self) -> Scope {
+        let mut state = self.state;
+        let framebuffer_viewport = match (&state.viewport, &self.framebuffer) {
+            (&None, Some(framebuffer)) => {
+                Some(Rect::new(0, 0, framebuffer.width as i32, framebuffer.height as i32))
+            },
+            _ => None
+        };
+        if let Some(rect) = framebuffer_viewport {
+            state.viewport = Some(Rc::new(Dynamic::Static(rect)));
+        }
+
+        Scope {
+            state: Rc::new(state),
+            framebuffer: self.framebuffer,
+            stack: self.stack,
+            backend: self.backend
+        }
+    }
+}
+
+/// Shared settings for the draw commands that run inside of it, e.g. a camera.
+///
+///     use rugl::recording_backend::RecordingBackend;
+///     use std::rc::Rc;
+///
+///     let rugl = rugl::init_with_backend(Rc::new(RecordingBackend::new()));
+///     let camera = rugl.scope()
+///         .uniform("projection", Box::new(|_| Box::new([1.0f32; 16])))
+///         .uniform("view", Box::new(|_| Box::new([1.0f32; 16])))
+///         .finalize();
+///
+///     let environment = rugl::Environment::new(100, 100);
+///     camera.run(&environment, |_| {
+///         // Commands that use `projection` and `view`, but don't set them, get the camera's.
+///     });
+///
+/// Scopes can be nested, and the settings of an inner scope win. Settings of the commands
+/// themselves always win over the settings of any scope.
+pub struct Scope {
+    state: Rc<ScopeState>,
+    framebuffer: Option<Rc<Framebuffer>>,
+    stack: ScopeStack,
+    backend: Rc<dyn GlBackend>
+}
+
+impl Scope {
+    /// Run the closure with the settings of the scope in effect, then restore the previous
+    /// settings and framebuffer, even if the closure panics.
+    pub fn run<F, R>(&self, environment: &Environment, inner: F) -> R where
+        F: FnOnce(&Environment) -> R
+    {
+        // Binding a framebuffer changes both the draw and the read binding.
+        let previous_framebuffers = match self.framebuffer {
+            Some(ref framebuffer) => {
+                let draw = self.backend.get_integer_v(gl::DRAW_FRAMEBUFFER_BINDING) as GLuint;
+                let read = self.backend.get_integer_v(gl::READ_FRAMEBUFFER_BINDING) as GLuint;
+                framebuffer.bind();
+                Some((draw, read))
+            },
+            None => None
+        };
+
+        self.stack.push(self.state.clone());
+        let _guard = ScopeGuard {
+            scope: self,
+            previous_framebuffers
+        };
+        inner(environment)
+    }
+}
+
+/// Leaves a running scope when it's dropped, so that a panic inside of the scope can't leave
+/// its settings on the stack.
+struct ScopeGuard<'a> {
+    scope: &'a Scope,
+    previous_framebuffers: Option<(GLuint, GLuint)>
+}
+
+impl<'a> Drop for ScopeGuard<'a> {
+    fn drop(&mut self) {
+        self.scope.stack.pop();
+
+        if let Some((draw, read)) = self.previous_framebuffers {
+            log_draw!("gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, {})", draw);
+            self.scope.backend.bind_framebuffer(gl::DRAW_FRAMEBUFFER, draw);
+            log_draw!("gl::BindFramebuffer(gl::READ_FRAMEBUFFER, {})", read);
+            self.scope.backend.bind_framebuffer(gl::READ_FRAMEBUFFER, read);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::recording_backend::RecordingBackend;
+    use super::super::state::Cull;
+    use std::panic;
+
+    fn scope(backend: &Rc<RecordingBackend>, stack: &ScopeStack) -> ScopeBuilder {
+        ScopeBuilder::new(backend.clone(), stack.clone())
+    }
+
+    /// The call that the innermost uniform of that name makes.
+    fn uniform_call(stack: &ScopeStack, name: &str, environment: &Environment) -> Option<String> {
+        stack.uniform(name).map(|setter| {
+            let backend = RecordingBackend::new();
+            setter(environment).set_uniform(&backend, 0, gl::FLOAT, 1);
+            backend.calls().remove(0)
+        })
+    }
+
+    #[test]
+    fn inner_scopes_win_over_outer_scopes() {
+        let backend = Rc::new(RecordingBackend::new());
+        let stack = ScopeStack::new();
+        let outer = scope(&backend, &stack)
+            .uniform("time", Box::new(|_| Box::new(1.0f32)))
+            .uniform("color", Box::new(|_| Box::new(2.0f32)))
+            .depth(Depth::disabled())
+            .finalize();
+        let inner = scope(&backend, &stack)
+            .uniform("color", Box::new(|_| Box::new(3.0f32)))
+            .cull(Cull::back())
+            .finalize();
+        let environment = Environment::new(100, 100);
+
+        outer.run(&environment, |env| {
+            inner.run(env, |env| {
+                assert_eq!(uniform_call(&stack, "time", env), Some("Uniform1fv(0, [1.0])".into()));
+                assert_eq!(uniform_call(&stack, "color", env), Some("Uniform1fv(0, [3.0])".into()));
+                assert_eq!(stack.find(|scope| scope.depth), Some(Depth::disabled()));
+                assert_eq!(stack.find(|scope| scope.cull), Some(Cull::back()));
+            });
+            assert_eq!(uniform_call(&stack, "color", env), Some("Uniform1fv(0, [2.0])".into()));
+            assert_eq!(stack.find(|scope| scope.cull), None);
+        });
+        assert_eq!(uniform_call(&stack, "time", &environment), None);
+        assert_eq!(stack.find(|scope| scope.depth), None);
+    }
+
+    #[test]
+    fn the_previous_framebuffers_are_restored() {
+        let backend = Rc::new(RecordingBackend::new());
+        let stack = ScopeStack::new();
+        let framebuffer = Rc::new(Framebuffer::new(backend.clone(), 64, 32));
+        let offscreen = scope(&backend, &stack).framebuffer(framebuffer).finalize();
+        backend.set_integer(gl::DRAW_FRAMEBUFFER_BINDING, 7);
+        backend.set_integer(gl::READ_FRAMEBUFFER_BINDING, 8);
+
+        backend.clear_calls();
+        offscreen.run(&Environment::new(100, 100), |_| {
+            assert_eq!(
+                stack.find(|scope| scope.viewport.clone()).map(|viewport| viewport.get(
+                    &Environment::new(100, 100), &()
+                )),
+                Some(Rect::new(0, 0, 64, 32))
+            );
+        });
+
+        let calls = backend.calls();
+        assert_eq!(&calls[calls.len() - 2..], &[
+            format!("BindFramebuffer({}, 7)", gl::DRAW_FRAMEBUFFER),
+            format!("BindFramebuffer({}, 8)", gl::READ_FRAMEBUFFER)
+        ]);
+    }
+
+    #[test]
+    fn a_panic_inside_of_a_scope_leaves_it() {
+        let backend = Rc::new(RecordingBackend::new());
+        let stack = ScopeStack::new();
+        let culled = scope(&backend, &stack).cull(Cull::back()).finalize();
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            culled.run(&Environment::new(100, 100), |_| panic!("The draw failed"));
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(stack.find(|scope| scope.cull), None);
+    }
+}
@@ -0,0 +1,654 @@
+use super::backend::GlBackend;
+use super::gl;
+use super::gl::types::*;
+use super::rugl::Environment;
+
+/// How a value is compared against the one that is already in a buffer, e.g. the depth of a
+/// fragment against the depth buffer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Comparison {
+    Never,
+    Less,
+    Equal,
+    LessEqual,
+    Greater,
+    NotEqual,
+    GreaterEqual,
+    Always
+}
+
+impl Comparison {
+    pub fn to_gl_enum(&self) -> GLenum {
+        match *self {
+            Comparison::Never => gl::NEVER,
+            Comparison::Less => gl::LESS,
+            Comparison::Equal => gl::EQUAL,
+            Comparison::LessEqual => gl::LEQUAL,
+            Comparison::Greater => gl::GREATER,
+            Comparison::NotEqual => gl::NOTEQUAL,
+            Comparison::GreaterEqual => gl::GEQUAL,
+            Comparison::Always => gl::ALWAYS
+        }
+    }
+}
+
+/// The depth test of a draw command. Commands that don't configure it get the default, which
+/// tests with `Comparison::Less` and writes to the depth buffer.
+///
+///     use rugl::state::{Comparison, Depth};
+///
+///     // Draw on top of everything, without hiding anything drawn afterwards.
+///     let overlay = Depth::disabled();
+///
+///     // Draw transparent geometry that is hidden by opaque geometry, but not by itself.
+///     let transparent = Depth {
+///         func: Comparison::LessEqual,
+///         mask: false,
+///         ..Depth::default()
+///     };
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Depth {
+    pub enable: bool,
+    pub func: Comparison,
+    /// Whether fragments that pass the test write their depth.
+    pub mask: bool,
+    /// The near and far values that normalized device coordinates are mapped to.
+    pub range: (f64, f64)
+}
+
+impl Default for Depth {
+    fn default() -> Depth {
+        Depth {
+            enable: true,
+            func: Comparison::Less,
+            mask: true,
+            range: (0.0, 1.0)
+        }
+    }
+}
+
+impl Depth {
+    pub fn disabled() -> Depth {
+        Depth {
+            enable: false,
+            ..Depth::default()
+        }
+    }
+
+    pub fn apply(&self, backend: &dyn GlBackend) {
+        if !self.enable {
+            log_draw!("gl::Disable(gl::DEPTH_TEST)");
+            backend.disable(gl::DEPTH_TEST);
+            return;
+        }
+        log_draw!("gl::Enable(gl::DEPTH_TEST)");
+        backend.enable(gl::DEPTH_TEST);
+        log_draw!("gl::DepthFunc({:?})", self.func);
+        backend.depth_func(self.func.to_gl_enum());
+        log_draw!("gl::DepthMask({})", self.mask);
+        backend.depth_mask(self.mask);
+        log_draw!("gl::DepthRange({}, {})", self.range.0, self.range.1);
+        backend.depth_range(self.range.0, self.range.1);
+    }
+}
+
+/// How the blended source and destination colors are combined.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BlendEquation {
+    Add,
+    Subtract,
+    ReverseSubtract,
+    Min,
+    Max
+}
+
+impl BlendEquation {
+    pub fn to_gl_enum(&self) -> GLenum {
+        match *self {
+            BlendEquation::Add => gl::FUNC_ADD,
+            BlendEquation::Subtract => gl::FUNC_SUBTRACT,
+            BlendEquation::ReverseSubtract => gl::FUNC_REVERSE_SUBTRACT,
+            BlendEquation::Min => gl::MIN,
+            BlendEquation::Max => gl::MAX
+        }
+    }
+}
+
+/// What the source and destination colors are multiplied by before they are combined.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BlendFactor {
+    Zero,
+    One,
+    SrcColor,
+    OneMinusSrcColor,
+    DstColor,
+    OneMinusDstColor,
+    SrcAlpha,
+    OneMinusSrcAlpha,
+    DstAlpha,
+    OneMinusDstAlpha,
+    ConstantColor,
+    OneMinusConstantColor,
+    ConstantAlpha,
+    OneMinusConstantAlpha,
+    SrcAlphaSaturate
+}
+
+impl BlendFactor {
+    pub fn to_gl_enum(&self) -> GLenum {
+        match *self {
+            BlendFactor::Zero => gl::ZERO,
+            BlendFactor::One => gl::ONE,
+            BlendFactor::SrcColor => gl::SRC_COLOR,
+            BlendFactor::OneMinusSrcColor => gl::ONE_MINUS_SRC_COLOR,
+            BlendFactor::DstColor => gl::DST_COLOR,
+            BlendFactor::OneMinusDstColor => gl::ONE_MINUS_DST_COLOR,
+            BlendFactor::SrcAlpha => gl::SRC_ALPHA,
+            BlendFactor::OneMinusSrcAlpha => gl::ONE_MINUS_SRC_ALPHA,
+            BlendFactor::DstAlpha => gl::DST_ALPHA,
+            BlendFactor::OneMinusDstAlpha => gl::ONE_MINUS_DST_ALPHA,
+            BlendFactor::ConstantColor => gl::CONSTANT_COLOR,
+            BlendFactor::OneMinusConstantColor => gl::ONE_MINUS_CONSTANT_COLOR,
+            BlendFactor::ConstantAlpha => gl::CONSTANT_ALPHA,
+            BlendFactor::OneMinusConstantAlpha => gl::ONE_MINUS_CONSTANT_ALPHA,
+            BlendFactor::SrcAlphaSaturate => gl::SRC_ALPHA_SATURATE
+        }
+    }
+}
+
+/// Blending of a draw command, which is off by default. The color and alpha channels each get
+/// their own equation and factors, and `color` is the constant used by the `Constant*`
+/// factors.
+///
+///     use rugl::state::{Blend, BlendFactor};
+///
+///     // Fade out everything that was drawn before by the command's alpha.
+///     let fade = Blend {
+///         src_rgb: BlendFactor::Zero,
+///         dst_rgb: BlendFactor::OneMinusSrcAlpha,
+///         ..Blend::alpha()
+///     };
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Blend {
+    pub enable: bool,
+    pub equation_rgb: BlendEquation,
+    pub equation_alpha: BlendEquation,
+    pub src_rgb: BlendFactor,
+    pub src_alpha: BlendFactor,
+    pub dst_rgb: BlendFactor,
+    pub dst_alpha: BlendFactor,
+    pub color: [f32; 4]
+}
+
+impl Default for Blend {
+    fn default() -> Blend {
+        Blend {
+            enable: false,
+            equation_rgb: BlendEquation::Add,
+            equation_alpha: BlendEquation::Add,
+            src_rgb: BlendFactor::One,
+            src_alpha: BlendFactor::One,
+            dst_rgb: BlendFactor::Zero,
+            dst_alpha: BlendFactor::Zero,
+            color: [0.0, 0.0, 0.0, 0.0]
+        }
+    }
+}
+
+impl Blend {
+    /// Regular alpha blending, where the alpha is the opacity of the color.
+    pub fn alpha() -> Blend {
+        Blend {
+            enable: true,
+            src_rgb: BlendFactor::SrcAlpha,
+            src_alpha: BlendFactor::One,
+            dst_rgb: BlendFactor::OneMinusSrcAlpha,
+            dst_alpha: BlendFactor::OneMinusSrcAlpha,
+            ..Blend::default()
+        }
+    }
+
+    /// Add the colors together, e.g. for glowing particles.
+    pub fn additive() -> Blend {
+        Blend {
+            enable: true,
+            src_rgb: BlendFactor::One,
+            src_alpha: BlendFactor::One,
+            dst_rgb: BlendFactor::One,
+            dst_alpha: BlendFactor::One,
+            ..Blend::default()
+        }
+    }
+
+    pub fn apply(&self, backend: &dyn GlBackend) {
+        if !self.enable {
+            log_draw!("gl::Disable(gl::BLEND)");
+            backend.disable(gl::BLEND);
+            return;
+        }
+        log_draw!("gl::Enable(gl::BLEND)");
+        backend.enable(gl::BLEND);
+        log_draw!("gl::BlendEquationSeparate({:?}, {:?})", self.equation_rgb, self.equation_alpha);
+        backend.blend_equation_separate(
+            self.equation_rgb.to_gl_enum(),
+            self.equation_alpha.to_gl_enum()
+        );
+        log_draw!(
+            "gl::BlendFuncSeparate({:?}, {:?}, {:?}, {:?})",
+            self.src_rgb, self.dst_rgb, self.src_alpha, self.dst_alpha
+        );
+        backend.blend_func_separate(
+            self.src_rgb.to_gl_enum(),
+            self.dst_rgb.to_gl_enum(),
+            self.src_alpha.to_gl_enum(),
+            self.dst_alpha.to_gl_enum()
+        );
+        log_draw!(
+            "gl::BlendColor({}, {}, {}, {})",
+            self.color[0], self.color[1], self.color[2], self.color[3]
+        );
+        backend.blend_color(self.color[0], self.color[1], self.color[2], self.color[3]);
+    }
+}
+
+/// Which side of a polygon something applies to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Face {
+    Front,
+    Back,
+    FrontAndBack
+}
+
+impl Face {
+    pub fn to_gl_enum(&self) -> GLenum {
+        match *self {
+            Face::Front => gl::FRONT,
+            Face::Back => gl::BACK,
+            Face::FrontAndBack => gl::FRONT_AND_BACK
+        }
+    }
+}
+
+/// Face culling of a draw command, which is off by default.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cull {
+    pub enable: bool,
+    /// The faces that aren't drawn.
+    pub face: Face
+}
+
+impl Default for Cull {
+    fn default() -> Cull {
+        Cull {
+            enable: false,
+            face: Face::Back
+        }
+    }
+}
+
+impl Cull {
+    /// Skip the back faces, which can never be seen on a closed mesh.
+    pub fn back() -> Cull {
+        Cull {
+            enable: true,
+            face: Face::Back
+        }
+    }
+
+    pub fn apply(&self, backend: &dyn GlBackend) {
+        if !self.enable {
+            log_draw!("gl::Disable(gl::CULL_FACE)");
+            backend.disable(gl::CULL_FACE);
+            return;
+        }
+        log_draw!("gl::Enable(gl::CULL_FACE)");
+        backend.enable(gl::CULL_FACE);
+        log_draw!("gl::CullFace({:?})", self.face);
+        backend.cull_face(self.face.to_gl_enum());
+    }
+}
+
+/// The winding order of the vertices of a front facing polygon, as seen on screen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Default)]
+pub enum FrontFace {
+    Clockwise,
+    #[default]
+    CounterClockwise
+}
+
+
+impl FrontFace {
+    pub fn to_gl_enum(&self) -> GLenum {
+        match *self {
+            FrontFace::Clockwise => gl::CW,
+            FrontFace::CounterClockwise => gl::CCW
+        }
+    }
+
+    pub fn apply(&self, backend: &dyn GlBackend) {
+        log_draw!("gl::FrontFace({:?})", self);
+        backend.front_face(self.to_gl_enum());
+    }
+}
+
+/// Push the depth of filled polygons back (or forward with negative values), so that lines
+/// drawn over the same geometry don't fight over the depth. The offset is
+/// `factor * slope + units * smallest_depth_difference`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PolygonOffset {
+    pub enable: bool,
+    pub factor: f32,
+    pub units: f32
+}
+
+impl Default for PolygonOffset {
+    fn default() -> PolygonOffset {
+        PolygonOffset {
+            enable: false,
+            factor: 0.0,
+            units: 0.0
+        }
+    }
+}
+
+impl PolygonOffset {
+    pub fn new(factor: f32, units: f32) -> PolygonOffset {
+        PolygonOffset {
+            enable: true,
+            factor,
+            units
+        }
+    }
+
+    pub fn apply(&self, backend: &dyn GlBackend) {
+        if !self.enable {
+            log_draw!("gl::Disable(gl::POLYGON_OFFSET_FILL)");
+            backend.disable(gl::POLYGON_OFFSET_FILL);
+            return;
+        }
+        log_draw!("gl::Enable(gl::POLYGON_OFFSET_FILL)");
+        backend.enable(gl::POLYGON_OFFSET_FILL);
+        log_draw!("gl::PolygonOffset({}, {})", self.factor, self.units);
+        backend.polygon_offset(self.factor, self.units);
+    }
+}
+
+/// What happens to the value in the stencil buffer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StencilOp {
+    Keep,
+    Zero,
+    /// Write the reference value of the stencil function.
+    Replace,
+    /// Add one, stopping at the maximum value.
+    Increment,
+    /// Add one, wrapping around to 0.
+    IncrementWrap,
+    Decrement,
+    DecrementWrap,
+    Invert
+}
+
+impl StencilOp {
+    pub fn to_gl_enum(&self) -> GLenum {
+        match *self {
+            StencilOp::Keep => gl::KEEP,
+            StencilOp::Zero => gl::ZERO,
+            StencilOp::Replace => gl::REPLACE,
+            StencilOp::Increment => gl::INCR,
+            StencilOp::IncrementWrap => gl::INCR_WRAP,
+            StencilOp::Decrement => gl::DECR,
+            StencilOp::DecrementWrap => gl::DECR_WRAP,
+            StencilOp::Invert => gl::INVERT
+        }
+    }
+}
+
+/// The stencil operations for one side of a polygon.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StencilOps {
+    /// When the stencil test fails.
+    pub fail: StencilOp,
+    /// When the stencil test passes, but the depth test fails.
+    pub zfail: StencilOp,
+    /// When both the stencil and depth tests pass.
+    pub zpass: StencilOp
+}
+
+impl Default for StencilOps {
+    fn default() -> StencilOps {
+        StencilOps {
+            fail: StencilOp::Keep,
+            zfail: StencilOp::Keep,
+            zpass: StencilOp::Keep
+        }
+    }
+}
+
+/// The stencil test of a draw command, which is off by default. A fragment passes when
+/// `reference & func_mask` compares successfully against `stencil & func_mask`.
+///
+///     use rugl::state::{Comparison, Stencil, StencilOp, StencilOps};
+///
+///     // Mark every pixel that an object covers with a 1.
+///     let write_ones = Stencil {
+///         enable: true,
+///         reference: 1,
+///         front: StencilOps { zpass: StencilOp::Replace, ..StencilOps::default() },
+///         back: StencilOps { zpass: StencilOp::Replace, ..StencilOps::default() },
+///         ..Stencil::default()
+///     };
+///
+///     // Then only draw outside of the marked pixels, e.g. for an outline.
+///     let outside_ones = Stencil {
+///         enable: true,
+///         func: Comparison::NotEqual,
+///         reference: 1,
+///         ..Stencil::default()
+///     };
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stencil {
+    pub enable: bool,
+    /// Which bits of the stencil buffer can be written.
+    pub mask: u32,
+    pub func: Comparison,
+    pub reference: i32,
+    /// Which bits are compared by the stencil function.
+    pub func_mask: u32,
+    pub front: StencilOps,
+    pub back: StencilOps
+}
+
+impl Default for Stencil {
+    fn default() -> Stencil {
+        Stencil {
+            enable: false,
+            mask: 0xff,
+            func: Comparison::Always,
+            reference: 0,
+            func_mask: 0xff,
+            front: StencilOps::default(),
+            back: StencilOps::default()
+        }
+    }
+}
+
+impl Stencil {
+    pub fn apply(&self, backend: &dyn GlBackend) {
+        if !self.enable {
+            log_draw!("gl::Disable(gl::STENCIL_TEST)");
+            backend.disable(gl::STENCIL_TEST);
+            return;
+        }
+        log_draw!("gl::Enable(gl::STENCIL_TEST)");
+        backend.enable(gl::STENCIL_TEST);
+        log_draw!("gl::StencilMask({})", self.mask);
+        backend.stencil_mask(self.mask);
+        log_draw!("gl::StencilFunc({:?}, {}, {})", self.func, self.reference, self.func_mask);
+        backend.stencil_func(self.func.to_gl_enum(), self.reference, self.func_mask);
+        for &(face, ops) in [(gl::FRONT, self.front), (gl::BACK, self.back)].iter() {
+            log_draw!("gl::StencilOpSeparate({}, {:?})", face, ops);
+            backend.stencil_op_separate(
+                face,
+                ops.fail.to_gl_enum(),
+                ops.zfail.to_gl_enum(),
+                ops.zpass.to_gl_enum()
+            );
+        }
+    }
+}
+
+/// The ranges of settings that the GL implementation supports, which are queried once when
+/// the context is created.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Limits {
+    pub line_width_range: (f32, f32),
+    pub point_size_range: (f32, f32)
+}
+
+impl Limits {
+    pub fn query(backend: &dyn GlBackend) -> Limits {
+        let mut line_width_range = [1.0, 1.0];
+        backend.get_float_v(gl::ALIASED_LINE_WIDTH_RANGE, &mut line_width_range);
+        let mut point_size_range = [1.0, 1.0];
+        backend.get_float_v(gl::POINT_SIZE_RANGE, &mut point_size_range);
+
+        log_draw!("Line width range {:?}, point size range {:?}", line_width_range, point_size_range);
+        Limits {
+            line_width_range: (line_width_range[0], line_width_range[1]),
+            point_size_range: (point_size_range[0], point_size_range[1])
+        }
+    }
+}
+
+/// Computes a value from the environment and the props that a command is called with.
+pub type PropsFunction<P, T> = Box<dyn Fn(&Environment, &P) -> T>;
+
+/// A setting that is either fixed when the command is built, or computed every time the
+/// command runs, from the environment or from the props that the command is called with.
+///
+///     use rugl::state::{Dynamic, Rect};
+///
+///     let fixed: Dynamic<Rect> = Rect::new(0, 0, 200, 200).into();
+///     let left_half: Dynamic<Rect> = Dynamic::Function(Box::new(|env| {
+///         Rect::new(0, 0, env.viewport_width as i32 / 2, env.viewport_height as i32)
+///     }));
+///     let from_props: Dynamic<Rect, Rect> = Dynamic::Props(Box::new(|_, rect| *rect));
+pub enum Dynamic<T, P = ()> {
+    Static(T),
+    Function(Box<dyn Fn(&Environment) -> T>),
+    Props(PropsFunction<P, T>)
+}
+
+impl<T: Copy, P> Dynamic<T, P> {
+    pub fn get(&self, environment: &Environment, props: &P) -> T {
+        match self {
+            &Dynamic::Static(value) => value,
+            Dynamic::Function(function) => function(environment),
+            Dynamic::Props(function) => function(environment, props)
+        }
+    }
+}
+
+impl<T, P> From<T> for Dynamic<T, P> {
+    fn from(value: T) -> Dynamic<T, P> {
+        Dynamic::Static(value)
+    }
+}
+
+/// A rectangle in pixels, measured from the bottom left of the render target like the rest
+/// of the GL.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32
+}
+
+impl Rect {
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height
+        }
+    }
+
+    /// Cover the whole viewport of the environment.
+    pub fn full(environment: &Environment) -> Rect {
+        Rect::new(0, 0, environment.viewport_width as i32, environment.viewport_height as i32)
+    }
+
+    pub fn apply_viewport(&self, backend: &dyn GlBackend) {
+        log_draw!("gl::Viewport({}, {}, {}, {})", self.x, self.y, self.width, self.height);
+        backend.viewport(self.x, self.y, self.width, self.height);
+    }
+
+    /// Enable the scissor test, and only allow drawing inside of the rectangle.
+    pub fn apply_scissor(&self, backend: &dyn GlBackend) {
+        log_draw!("gl::Enable(gl::SCISSOR_TEST)");
+        backend.enable(gl::SCISSOR_TEST);
+        log_draw!("gl::Scissor({}, {}, {}, {})", self.x, self.y, self.width, self.height);
+        backend.scissor(self.x, self.y, self.width, self.height);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::recording_backend::RecordingBackend;
+
+    #[test]
+    fn blending_is_only_configured_when_enabled() {
+        let backend = RecordingBackend::new();
+        Blend::default().apply(&backend);
+        assert_eq!(backend.calls(), vec![format!("Disable({})", gl::BLEND)]);
+
+        backend.clear_calls();
+        Blend::alpha().apply(&backend);
+        assert_eq!(backend.calls(), vec![
+            format!("Enable({})", gl::BLEND),
+            format!("BlendEquationSeparate({}, {})", gl::FUNC_ADD, gl::FUNC_ADD),
+            format!(
+                "BlendFuncSeparate({}, {}, {}, {})",
+                gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA, gl::ONE, gl::ONE_MINUS_SRC_ALPHA
+            ),
+            "BlendColor(0.0, 0.0, 0.0, 0.0)".to_string()
+        ]);
+    }
+
+    #[test]
+    fn stencil_ops_are_set_separately_for_each_face() {
+        let backend = RecordingBackend::new();
+        Stencil::default().apply(&backend);
+        assert_eq!(backend.calls(), vec![format!("Disable({})", gl::STENCIL_TEST)]);
+
+        backend.clear_calls();
+        Stencil {
+            enable: true,
+            mask: 0x0f,
+            func: Comparison::Equal,
+            reference: 1,
+            func_mask: 0x03,
+            front: StencilOps { zpass: StencilOp::Replace, ..StencilOps::default() },
+            back: StencilOps {
+                fail: StencilOp::Zero,
+                zfail: StencilOp::DecrementWrap,
+                zpass: StencilOp::IncrementWrap
+            }
+        }.apply(&backend);
+        assert_eq!(backend.calls(), vec![
+            format!("Enable({})", gl::STENCIL_TEST),
+            "StencilMask(15)".to_string(),
+            format!("StencilFunc({}, 1, 3)", gl::EQUAL),
+            format!("StencilOpSeparate({}, {}, {}, {})", gl::FRONT, gl::KEEP, gl::KEEP, gl::REPLACE),
+            format!(
+                "StencilOpSeparate({}, {}, {}, {})",
+                gl::BACK, gl::ZERO, gl::DECR_WRAP, gl::INCR_WRAP
+            )
+        ]);
+    }
+}